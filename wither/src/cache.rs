@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use mongodb::bson::Document;
+
+use crate::error::Result;
+
+/// A trait describing a cache which may be placed in front of model lookups.
+///
+/// Implement this trait over your cache of choice, EG an in-memory map or a redis client, to use
+/// `Model::find_by_id_cached`. Instances are cached in their serialized form, as produced by
+/// `Model::document_from_instance`. Implementations should report their own failures as
+/// `WitherError::Cache`.
+#[async_trait]
+pub trait CacheProvider: Send + Sync {
+    /// Get the document cached under the given key, if any.
+    async fn get(&self, key: &str) -> Result<Option<Document>>;
+
+    /// Cache the given document under the given key.
+    async fn set(&self, key: &str, value: Document) -> Result<()>;
+}
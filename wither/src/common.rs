@@ -1,4 +1,9 @@
-use crate::bson::Document;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::bson::oid::ObjectId;
+use crate::bson::{Bson, Document};
+use crate::mongodb::options::{UpdateModifications, WriteConcern};
 
 /// A placeholder for the standard `IndexModel`, which is currently not present in the mongodb
 /// driver.
@@ -17,3 +22,163 @@ impl IndexModel {
         Self { keys, options }
     }
 }
+
+/// A typed summary of an index currently present on a collection, as returned by
+/// `Model::index_info`.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct IndexInfo {
+    /// The name of the index.
+    pub name: String,
+    /// The indexed fields, along with their sort order or index type.
+    pub keys: Document,
+    /// Whether the index enforces uniqueness.
+    pub unique: bool,
+    /// The time after which documents expire, for TTL indexes.
+    pub ttl: Option<Duration>,
+    /// Whether the index was requested to be built in the background.
+    pub background: bool,
+}
+
+/// The parameters of a capped collection, as declared via `Model::capped`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CappedOptions {
+    /// The maximum size of the collection, in bytes.
+    pub size: u64,
+    /// The maximum number of documents allowed in the collection, if any.
+    pub max: Option<u64>,
+}
+
+impl CappedOptions {
+    /// Create a new instance with the given maximum size in bytes & maximum document count.
+    pub fn new(size: u64, max: Option<u64>) -> Self {
+        Self { size, max }
+    }
+}
+
+/// A placeholder for the standard `WriteModel`, which is currently not present in the mongodb
+/// driver.
+///
+/// Each variant describes a single write operation to be issued as part of a bulk write.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum WriteModel {
+    /// Insert the given document.
+    InsertOne { document: Document },
+    /// Replace the first document matching the filter with the given replacement.
+    ReplaceOne { filter: Document, replacement: Document, upsert: bool },
+    /// Update the first document matching the filter.
+    UpdateOne {
+        filter: Document,
+        update: UpdateModifications,
+        upsert: bool,
+    },
+    /// Update all documents matching the filter.
+    UpdateMany {
+        filter: Document,
+        update: UpdateModifications,
+        upsert: bool,
+    },
+    /// Delete the first document matching the filter.
+    DeleteOne { filter: Document },
+    /// Delete all documents matching the filter.
+    DeleteMany { filter: Document },
+}
+
+/// Options to use for a bulk write.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct BulkWriteOptions {
+    /// Whether or not the writes should be executed in order, stopping at the first error.
+    /// Defaults to `true`.
+    pub ordered: Option<bool>,
+    /// Opt out of document-level validation.
+    pub bypass_document_validation: Option<bool>,
+    /// The write concern to use for the bulk write. Defaults to the model's write concern.
+    pub write_concern: Option<WriteConcern>,
+}
+
+/// The outcome of a call to `Model::save_outcome`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveOutcome {
+    /// A new document was inserted with the given ID.
+    Inserted { id: ObjectId },
+    /// An existing document with the given ID was replaced.
+    Replaced { id: ObjectId },
+}
+
+impl SaveOutcome {
+    /// The ID of the saved document.
+    pub fn id(&self) -> ObjectId {
+        match self {
+            Self::Inserted { id } | Self::Replaced { id } => *id,
+        }
+    }
+
+    /// Whether a new document was inserted.
+    pub fn is_inserted(&self) -> bool {
+        matches!(self, Self::Inserted { .. })
+    }
+}
+
+/// The verbosity of the plan data returned by `Model::explain_find`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExplainVerbosity {
+    /// Only the plan selected by the query optimizer.
+    QueryPlanner,
+    /// The selected plan, along with statistics from executing it.
+    ExecutionStats,
+    /// As `ExecutionStats`, along with partial statistics for the rejected candidate plans.
+    AllPlansExecution,
+}
+
+impl ExplainVerbosity {
+    /// The name of this verbosity, as used by the `explain` command.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::QueryPlanner => "queryPlanner",
+            Self::ExecutionStats => "executionStats",
+            Self::AllPlansExecution => "allPlansExecution",
+        }
+    }
+}
+
+/// Options to use when synchronizing a model's indexes via `Model::sync_with`.
+///
+/// The defaults match the behavior of `Model::sync`.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct SyncOptions {
+    /// Whether to adopt an existing index which has the same keys & options as a declared index,
+    /// but a different name, rather than dropping it & creating the declared index in its place.
+    /// The adopted index keeps its name. Defaults to `false`.
+    pub adopt_renamed_indexes: bool,
+    /// The `commitQuorum` to use for the `createIndexes` command, EG `"majority"`,
+    /// `"votingMembers"` or a number of data-bearing members. Requires MongoDB 4.4+ on a replica
+    /// set. Defaults to the server's own default.
+    pub commit_quorum: Option<Bson>,
+    /// Whether to log the names of the indexes being dropped & created, and when each step has
+    /// completed. Defaults to `false`.
+    pub log_progress: bool,
+    /// Whether to drop & recreate the model's capped collection when its `size` or `max` differs
+    /// from `Model::capped`, provided the collection holds no documents. Non-empty collections are
+    /// never dropped. Defaults to `false`.
+    pub recreate_empty_capped: bool,
+}
+
+/// The result of a bulk write.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct BulkWriteResult {
+    /// The number of documents inserted.
+    pub inserted_count: u64,
+    /// The number of documents matched by update & replace operations.
+    pub matched_count: u64,
+    /// The number of documents modified by update & replace operations.
+    pub modified_count: u64,
+    /// The number of documents deleted.
+    pub deleted_count: u64,
+    /// The IDs of any upserted documents, keyed by the index of the corresponding write model.
+    pub upserted_ids: HashMap<usize, Bson>,
+}
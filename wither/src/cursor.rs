@@ -1,22 +1,106 @@
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
-use futures::stream::Stream;
+use futures::stream::{Stream, StreamExt};
+use mongodb::bson::{from_document, Document};
 use mongodb::Cursor;
 use serde::de::DeserializeOwned;
 
 use crate::error::{Result, WitherError};
-use crate::Model;
 
 /// A cursor of model documents.
+///
+/// This cursor may also yield instances of types other than the model itself, such as when a
+/// projection is used to load only a subset of a model's fields. Documents are deserialized
+/// lazily as they are yielded from the underlying cursor.
+///
+/// A document which fails to deserialize is yielded as a `WitherError::DeserializeWithId`,
+/// carrying the document's `_id` to help locate it, unless `on_error_skip` is used.
+///
+/// The cursor's batching & timeout behavior is configured through the `FindOptions` used to
+/// create it, see `Model::find_with`.
+///
+/// As this cursor is a stream of `Result`s, the `futures::TryStreamExt` combinators may be used
+/// directly, EG `cursor.try_collect::<Vec<_>>().await` yields a `Result<Vec<T>>`.
 pub struct ModelCursor<T: DeserializeOwned + Unpin + Send + Sync> {
-    cursor: Cursor<T>,
+    cursor: Cursor<Document>,
+    /// Whether documents which fail to deserialize should be skipped, rather than yielded as errors.
+    skip_errors: bool,
     marker: std::marker::PhantomData<T>,
 }
 
-impl<T: Model + DeserializeOwned + Unpin + Send + Sync> ModelCursor<T> {
-    pub(crate) fn new(cursor: Cursor<T>) -> Self {
-        Self { cursor, marker: std::marker::PhantomData }
+impl<T: DeserializeOwned + Unpin + Send + Sync> ModelCursor<T> {
+    pub(crate) fn new(cursor: Cursor<Document>) -> Self {
+        Self {
+            cursor,
+            skip_errors: false,
+            marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Configure this cursor to log & skip documents which fail to deserialize, rather than
+    /// yielding them as errors.
+    ///
+    /// Errors from the underlying cursor itself are still yielded. This is useful when iterating
+    /// over collections which may hold malformed historical documents.
+    pub fn on_error_skip(mut self) -> Self {
+        self.skip_errors = true;
+        self
+    }
+
+    /// Re-target this cursor to deserialize its remaining documents as instances of `U`.
+    ///
+    /// This is useful for polymorphic reads, where documents from a model's collection are to be
+    /// read as a different type for a specific read path. Any documents already yielded are not
+    /// affected, and the `on_error_skip` setting of this cursor is retained.
+    pub fn into_typed<U: DeserializeOwned + Unpin + Send + Sync>(self) -> ModelCursor<U> {
+        ModelCursor {
+            cursor: self.cursor,
+            skip_errors: self.skip_errors,
+            marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Get the next model from this cursor, or `None` if the cursor is exhausted.
+    ///
+    /// This flattens the stream's `Option<Result<T>>` into `Result<Option<T>>`, which allows for
+    /// `while let Some(model) = cursor.try_next().await? {}` style iteration.
+    pub async fn try_next(&mut self) -> Result<Option<T>> {
+        self.next().await.transpose()
+    }
+
+    /// Map each successfully yielded model of this cursor using the given function.
+    ///
+    /// Errors are passed through the returned stream unchanged.
+    pub fn map_ok<U, F>(self, mut f: F) -> impl Stream<Item = Result<U>>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.map(move |res| res.map(&mut f))
+    }
+
+    /// Group the models of this cursor into batches of up to `size` models each.
+    ///
+    /// Each batch holds exactly `size` models, except for the last, which holds whatever remains.
+    /// The first error encountered is yielded in place of its batch & ends the stream, discarding
+    /// any models already accumulated for that batch. Unlike `StreamExt::chunks`, which batches
+    /// each `Result` individually, this yields a single `Result` per batch, bounding memory use
+    /// to one batch. Panics if `size` is zero.
+    pub fn chunks(self, size: usize) -> impl Stream<Item = Result<Vec<T>>> {
+        assert!(size > 0, "chunk size must be greater than zero");
+        futures::stream::unfold(Some(self), move |cursor| async move {
+            let mut cursor = cursor?;
+            let mut chunk = vec![];
+            while chunk.len() < size {
+                match cursor.next().await {
+                    Some(Ok(model)) => chunk.push(model),
+                    Some(Err(err)) => return Some((Err(err), None)),
+                    None if chunk.is_empty() => return None,
+                    None => return Some((Ok(chunk), None)),
+                }
+            }
+            Some((Ok(chunk), Some(cursor)))
+        })
     }
 }
 
@@ -24,20 +108,30 @@ impl<T: Model + DeserializeOwned + Unpin + Send + Sync> ModelCursor<T> {
 // only the underlying `Cursor` needs to remain pinned while we poll from this vantage point.
 impl<T: DeserializeOwned + Unpin + Send + Sync> Unpin for ModelCursor<T> {}
 
-impl<T: Model + DeserializeOwned + Unpin + Send + Sync> Stream for ModelCursor<T> {
+impl<T: DeserializeOwned + Unpin + Send + Sync> Stream for ModelCursor<T> {
     type Item = Result<T>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let doc = match Pin::new(&mut self.cursor).poll_next(cx) {
-            Poll::Pending => return Poll::Pending,
-            Poll::Ready(None) => return Poll::Ready(None),
-            Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(WitherError::from(err)))),
-            Poll::Ready(Some(Ok(doc))) => doc,
-        };
-        // match Model::instance_from_document(doc) {
-        // Ok(model) => Poll::Ready(Some(Ok(model))),
-        // Err(err) => Poll::Ready(Some(Err(err))),
-        // }
-        Poll::Ready(Some(Ok(doc)))
+        loop {
+            let doc = match Pin::new(&mut self.cursor).poll_next(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(WitherError::from(err)))),
+                Poll::Ready(Some(Ok(doc))) => doc,
+            };
+            // Retain the document's ID for context, should it fail to deserialize.
+            let id = doc.get("_id").cloned();
+            match from_document::<T>(doc) {
+                Ok(model) => return Poll::Ready(Some(Ok(model))),
+                Err(err) if self.skip_errors => {
+                    warn!("Skipping document {:?} which failed to deserialize: {}", id, err);
+                    continue;
+                }
+                Err(source) => {
+                    let id = id.map(Box::new);
+                    return Poll::Ready(Some(Err(WitherError::DeserializeWithId { id, source })));
+                }
+            }
+        }
     }
 }
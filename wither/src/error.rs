@@ -1,5 +1,15 @@
+use mongodb::error::{ErrorKind, WriteFailure, RETRYABLE_WRITE_ERROR, TRANSIENT_TRANSACTION_ERROR};
 use thiserror::Error;
 
+/// The server error code of a duplicate key error.
+const DUPLICATE_KEY_CODE: i32 = 11000;
+/// The server error code of a write conflict, as raised within transactions.
+const WRITE_CONFLICT_CODE: i32 = 112;
+/// The server error code of an index creation conflicting with an existing index's options.
+const INDEX_OPTIONS_CONFLICT_CODE: i32 = 85;
+/// The server error code of an index creation conflicting with an existing index's keys.
+const INDEX_KEY_SPECS_CONFLICT_CODE: i32 = 86;
+
 /// A `Result` type alias using `WitherError` instances as the error variant.
 pub type Result<T> = std::result::Result<T, WitherError>;
 
@@ -18,19 +28,177 @@ pub enum WitherError {
     /// A BSON serialization error.
     #[error("{0}")]
     BsonSer(#[from] mongodb::bson::ser::Error),
+    /// A BSON deserialization error of a document yielded by a cursor, along with the `_id` of
+    /// the offending document, if it has one.
+    #[error("Failed to deserialize document with _id {id:?}: {source}")]
+    DeserializeWithId {
+        /// The `_id` of the document which failed to deserialize, boxed to keep this error small.
+        id: Option<Box<mongodb::bson::Bson>>,
+        /// The underlying deserialization error.
+        source: mongodb::bson::de::Error,
+    },
     /// An error indicating that an ObjectId is required for the requested operation.
     #[error("Model must have an ObjectId for this operation.")]
     ModelIdRequiredForOperation,
+    /// An error indicating that an ObjectId must not already be present for the requested operation.
+    #[error("Model already has an ObjectId, which is not allowed for this operation.")]
+    ModelIdAlreadySet,
+    /// An error reported by a `CacheProvider` implementation.
+    #[error("Cache operation failed: {0}")]
+    Cache(String),
+    /// An error indicating that a model instance failed validation.
+    #[error("Model validation failed: {0}")]
+    Validation(String),
     /// An error indicating that a model was serialized to a BSON variant other than a document.
     #[error("Serializing model to BSON failed to produce a Bson::Document, got type {0:?}")]
     ModelSerToDocument(mongodb::bson::spec::ElementType),
     /// An error indicating that the server failed to return a document after an update.
     #[error("Server failed to return the updated document. Update may have failed.")]
     ServerFailedToReturnUpdatedDoc,
+    /// An error indicating that no document matching the operation's filter was found in the given collection.
+    #[error("No matching document was found in collection '{0}'.")]
+    DocumentNotFound(String),
     /// An error indicating that the server failed to return an ObjectId.
     #[error("Server failed to return ObjectId of updated document.")]
     ServerFailedToReturnObjectId,
     /// An error indicating that one of `$set` or `$unset` must be specified for a migration.
     #[error("One of '$set' or '$unset' must be specified.")]
     MigrationSetOrUnsetRequired,
+    /// An error indicating that a required field was not given when building a migration.
+    #[error("The migration field '{0}' must be specified.")]
+    MigrationFieldRequired(&'static str),
+    /// An error indicating that a non-empty pipeline must be specified for a pipeline migration.
+    #[error("A non-empty pipeline must be specified.")]
+    MigrationPipelineRequired,
+    /// An error indicating that a rename migration was given an empty field, or the same field
+    /// to rename from & to.
+    #[error("A rename migration requires distinct, non-empty 'from' & 'to' fields.")]
+    MigrationRenameInvalid,
+    /// An error indicating that multiple indexes declared on a model map to the same index name.
+    #[error("Multiple indexes declared on the model map to the index name '{0}'.")]
+    DuplicateIndexName(String),
+    /// An error indicating that an index could not be created, as an existing index covers the
+    /// same keys under a different name.
+    #[error("Index '{aspired}' conflicts with the existing index '{existing}', which has the same keys under a different name.")]
+    IndexNameConflict {
+        /// The name of the index which was to be created.
+        aspired: String,
+        /// The name of the existing index covering the same keys.
+        existing: String,
+    },
+    /// An error indicating that the model must declare a version field for the requested operation.
+    #[error("Model must declare a version field for this operation.")]
+    VersionFieldRequired,
+    /// An error indicating that an operation requires the model to declare a snapshot field.
+    #[error("Model must declare a snapshot field for this operation.")]
+    SnapshotFieldRequired,
+    /// An error indicating that a model's version field does not hold an integer.
+    #[error("The version field '{0}' must hold an integer, or be null.")]
+    InvalidVersionField(String),
+    /// An error indicating that a versioned save matched no document, as the stored document was
+    /// concurrently modified or deleted.
+    #[error("Version conflict: the document in collection '{0}' was modified or deleted concurrently.")]
+    VersionConflict(String),
+    /// An error indicating that a write was attempted on a read-only model.
+    #[error("Model for collection '{0}' is read-only.")]
+    ReadOnlyModel(String),
+}
+
+/// Errors are compared structurally, except for errors wrapping a driver or BSON error, which
+/// are never equal to any error, as their sources cannot be compared.
+impl PartialEq for WitherError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Mongo(_), _) | (Self::BsonOid(_), _) | (Self::BsonDe(_), _) | (Self::BsonSer(_), _) => false,
+            (Self::DeserializeWithId { .. }, _) => false,
+            (Self::Cache(a), Self::Cache(b)) => a == b,
+            (Self::Validation(a), Self::Validation(b)) => a == b,
+            (Self::ModelSerToDocument(a), Self::ModelSerToDocument(b)) => a == b,
+            (Self::DocumentNotFound(a), Self::DocumentNotFound(b)) => a == b,
+            (Self::MigrationFieldRequired(a), Self::MigrationFieldRequired(b)) => a == b,
+            (Self::DuplicateIndexName(a), Self::DuplicateIndexName(b)) => a == b,
+            (Self::IndexNameConflict { aspired: a, existing: x }, Self::IndexNameConflict { aspired: b, existing: y }) => a == b && x == y,
+            (Self::InvalidVersionField(a), Self::InvalidVersionField(b)) => a == b,
+            (Self::VersionConflict(a), Self::VersionConflict(b)) => a == b,
+            (Self::ReadOnlyModel(a), Self::ReadOnlyModel(b)) => a == b,
+            // All remaining variants carry no data.
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl WitherError {
+    /// Whether this is a duplicate key error, such as from a racing insert into a unique index.
+    pub fn is_duplicate_key(&self) -> bool {
+        self.server_error_codes().contains(&DUPLICATE_KEY_CODE)
+    }
+
+    /// Whether this error is transient, such as a write conflict, so that the failed operation
+    /// may succeed if retried.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Mongo(err) => {
+                err.contains_label(TRANSIENT_TRANSACTION_ERROR)
+                    || err.contains_label(RETRYABLE_WRITE_ERROR)
+                    || self.server_error_codes().contains(&WRITE_CONFLICT_CODE)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether this is a server error rejecting an index which conflicts with an existing index.
+    pub(crate) fn is_index_conflict(&self) -> bool {
+        let codes = self.server_error_codes();
+        codes.contains(&INDEX_OPTIONS_CONFLICT_CODE) || codes.contains(&INDEX_KEY_SPECS_CONFLICT_CODE)
+    }
+
+    /// The error codes reported by the server for this error, if any.
+    fn server_error_codes(&self) -> Vec<i32> {
+        let err = match self {
+            Self::Mongo(err) => err,
+            _ => return vec![],
+        };
+        match err.kind.as_ref() {
+            ErrorKind::Command(err) => vec![err.code],
+            ErrorKind::Write(WriteFailure::WriteError(err)) => vec![err.code],
+            ErrorKind::BulkWrite(failure) => failure.write_errors.iter().flatten().map(|err| err.code).collect(),
+            _ => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mongodb::bson::{doc, from_document};
+
+    fn command_error(code: i32) -> WitherError {
+        let err = from_document(doc! {"code": code, "codeName": "Test", "errmsg": "test"}).unwrap();
+        mongodb::error::Error::from(ErrorKind::Command(err)).into()
+    }
+
+    #[test]
+    fn error_classification_should_match_server_codes() {
+        assert!(command_error(DUPLICATE_KEY_CODE).is_duplicate_key());
+        assert!(!command_error(DUPLICATE_KEY_CODE).is_transient());
+        assert!(command_error(WRITE_CONFLICT_CODE).is_transient());
+        assert!(!command_error(WRITE_CONFLICT_CODE).is_duplicate_key());
+        assert!(!command_error(2).is_transient());
+        assert!(!WitherError::ModelIdRequiredForOperation.is_duplicate_key());
+    }
+
+    #[test]
+    fn partial_eq_should_compare_non_source_variants_structurally() {
+        assert_eq!(WitherError::ModelIdRequiredForOperation, WitherError::ModelIdRequiredForOperation);
+        assert_ne!(WitherError::ModelIdRequiredForOperation, WitherError::ModelIdAlreadySet);
+        assert_eq!(WitherError::Validation("bad".into()), WitherError::Validation("bad".into()));
+        assert_ne!(WitherError::Validation("bad".into()), WitherError::Validation("worse".into()));
+        assert_ne!(
+            WitherError::DocumentNotFound("users".into()),
+            WitherError::VersionConflict("users".into())
+        );
+        let wrapped = command_error(DUPLICATE_KEY_CODE);
+        assert_ne!(wrapped, command_error(DUPLICATE_KEY_CODE));
+        assert_ne!(wrapped, wrapped);
+    }
 }
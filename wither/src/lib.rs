@@ -6,27 +6,37 @@ pub use mongodb;
 pub use mongodb::bson;
 
 pub use wither_derive::Model;
-#[cfg(any(feature = "sync"))]
-pub use wither_derive::ModelSync;
 
 // Common //
 mod error;
+#[macro_use]
+mod logging;
 pub use error::{Result, WitherError};
 mod common;
-pub use common::IndexModel;
+pub use common::{BulkWriteOptions, BulkWriteResult, CappedOptions, ExplainVerbosity, IndexInfo, IndexModel, SaveOutcome, SyncOptions, WriteModel};
 
 // Async //
+mod cache;
+pub use cache::CacheProvider;
 mod cursor;
 pub use cursor::ModelCursor;
 
 mod migration;
-pub use migration::{IntervalMigration, Migration};
+pub use migration::{IntervalMigration, IntervalMigrationBuilder, Migration, MigrationReport, MigrationStatus, PipelineMigration, RenameMigration};
 mod model;
-pub use model::Model;
+pub use model::{Model, ModelExt};
+mod update;
+pub use update::UpdateBuilder;
+mod transaction;
+pub use transaction::with_transaction;
+pub mod util;
+mod validate;
+pub use validate::Validate;
 
 /// All traits needed for basic usage of the wither system.
 pub mod prelude {
     pub use crate::migration::{Migrating, Migration};
-    pub use crate::model::Model;
+    pub use crate::model::{Model, ModelExt};
+    pub use crate::validate::Validate;
     pub use wither_derive::Model;
 }
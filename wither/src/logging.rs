@@ -0,0 +1,48 @@
+//! Internal logging facade, routing through `log` by default, or `tracing` via the `tracing` feature.
+//!
+//! The `debug!`, `info!` & `warn!` macros are available throughout the crate via `#[macro_use]`.
+
+use std::future::Future;
+
+use mongodb::Namespace;
+
+macro_rules! debug {
+    ($($arg:tt)+) => {{
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($arg)+);
+        #[cfg(not(feature = "tracing"))]
+        log::debug!($($arg)+);
+    }};
+}
+
+macro_rules! info {
+    ($($arg:tt)+) => {{
+        #[cfg(feature = "tracing")]
+        tracing::info!($($arg)+);
+        #[cfg(not(feature = "tracing"))]
+        log::info!($($arg)+);
+    }};
+}
+
+macro_rules! warn {
+    ($($arg:tt)+) => {{
+        #[cfg(feature = "tracing")]
+        tracing::warn!($($arg)+);
+        #[cfg(not(feature = "tracing"))]
+        log::warn!($($arg)+);
+    }};
+}
+
+/// Drive the given future within a span for the given operation against the given namespace.
+#[cfg(feature = "tracing")]
+pub(crate) async fn instrument<F: Future>(operation: &'static str, namespace: Namespace, fut: F) -> F::Output {
+    use tracing::Instrument;
+    fut.instrument(tracing::info_span!("wither", operation, namespace = %namespace))
+        .await
+}
+
+/// Drive the given future; spans are only emitted when the `tracing` feature is enabled.
+#[cfg(not(feature = "tracing"))]
+pub(crate) async fn instrument<F: Future>(_operation: &'static str, _namespace: Namespace, fut: F) -> F::Output {
+    fut.await
+}
@@ -1,9 +1,16 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
 use async_trait::async_trait;
+use futures::stream::TryStreamExt;
 use mongodb::bson::{doc, Bson, Document};
-use mongodb::{options, Collection, Database};
+use mongodb::{options, Collection, Database, Namespace};
 
+use crate::common::IndexModel;
 use crate::error::{Result, WitherError};
-use crate::model::Model;
+use crate::logging;
+use crate::model::{ensure_indexes, Model};
 
 /// A trait describing a `Model` which has associated migrations.
 #[async_trait]
@@ -12,20 +19,69 @@ pub trait Migrating: Model {
     fn migrations() -> Vec<Box<dyn Migration<Self>>>;
 
     /// Execute all migrations for this model.
-    async fn migrate(db: &Database) -> Result<()> {
-        let coll = Self::collection(db);
-        let ns = coll.namespace();
-        let migrations = Self::migrations();
+    ///
+    /// Before each migration is executed, any of its `required_indexes` which do not yet exist on
+    /// the collection are created. Returns a report for each of the executed migrations, in the
+    /// order they were executed.
+    async fn migrate(db: &Database) -> Result<Vec<MigrationReport>> {
+        run_migrations::<Self>(db, false).await
+    }
 
-        // Execute each migration.
-        log::info!("Starting migrations for '{}'.", ns);
-        for migration in migrations {
-            migration.execute(&coll).await?;
+    /// Execute all migrations for this model, regardless of their thresholds.
+    ///
+    /// This behaves exactly as `Migrating::migrate`, but each migration is executed via
+    /// `Migration::execute_forced`, so that migrations whose `threshold` has passed are executed
+    /// rather than skipped. This is intended for controlled maintenance windows, where migrations
+    /// are knowingly run once more; the threshold logic is still what keeps rolling deploys safe.
+    async fn migrate_forced(db: &Database) -> Result<Vec<MigrationReport>> {
+        run_migrations::<Self>(db, true).await
+    }
+
+    /// Report which of this model's migrations `Migrating::migrate` would execute, without
+    /// modifying anything.
+    ///
+    /// This is a dry-run, intended as a preflight check before a deploy. For each migration, in
+    /// order, `Migration::status` reports whether it is pending, along with an estimate of the
+    /// number of documents it would affect.
+    async fn migration_status(db: &Database) -> Result<Vec<MigrationStatus>> {
+        let coll = Self::collection(db);
+        let mut statuses = vec![];
+        for migration in Self::migrations() {
+            statuses.push(migration.status(&coll).await?);
         }
+        Ok(statuses)
+    }
+}
 
-        log::info!("Finished migrations for '{}'.", ns);
-        Ok(())
+/// Execute all migrations of the given model, optionally forcing them past their thresholds.
+async fn run_migrations<M: Migrating>(db: &Database, force: bool) -> Result<Vec<MigrationReport>> {
+    let coll = M::collection(db);
+    logging::instrument("migrate", coll.namespace(), execute_migrations::<M>(db, &coll, force)).await
+}
+
+/// Execute each of the given model's migrations in turn, collecting their reports.
+async fn execute_migrations<M: Migrating>(db: &Database, coll: &Collection<M>, force: bool) -> Result<Vec<MigrationReport>> {
+    let ns = coll.namespace();
+    let migrations = M::migrations();
+
+    // Execute each migration.
+    info!("Starting migrations for '{}'.", ns);
+    let mut reports = Vec::with_capacity(migrations.len());
+    for migration in migrations {
+        // Only build the required indexes of migrations which will run, as `sync` drops them.
+        let indexes = migration.required_indexes();
+        if !indexes.is_empty() && (force || migration.status(coll).await?.pending) {
+            ensure_indexes(db, coll, indexes).await?;
+        }
+        let report = match force {
+            true => migration.execute_forced(coll).await?,
+            false => migration.execute(coll).await?,
+        };
+        reports.push(report);
     }
+
+    info!("Finished migrations for '{}'.", ns);
+    Ok(reports)
 }
 
 /// A trait describing objects which encapsulate a schema migration.
@@ -33,7 +89,82 @@ pub trait Migrating: Model {
 #[async_trait]
 pub trait Migration<T>: Send + Sync {
     /// The function which is to execute this migration.
-    async fn execute<'c>(&self, coll: &'c Collection<T>) -> Result<()>;
+    async fn execute<'c>(&self, coll: &'c Collection<T>) -> Result<MigrationReport>;
+
+    /// Execute this migration, even when it would otherwise be skipped, such as when its
+    /// threshold has passed.
+    ///
+    /// Used by `Migrating::migrate_forced`. Defaults to `Migration::execute`, which is correct for
+    /// migrations which are never skipped.
+    async fn execute_forced<'c>(&self, coll: &'c Collection<T>) -> Result<MigrationReport>
+    where
+        T: Sync,
+    {
+        self.execute(coll).await
+    }
+
+    /// Indexes which must exist before this migration is executed, such as to support its filter.
+    ///
+    /// `Migrating::migrate` creates any of these which are missing, matched by index name, unless
+    /// `Migration::status` reports the migration as no longer pending; existing indexes are never
+    /// dropped or modified. Declare these indexes on the model as well if they
+    /// should outlive the migration, as `sync` drops any index the model does not declare.
+    fn required_indexes(&self) -> Vec<IndexModel> {
+        vec![]
+    }
+
+    /// Report whether this migration would be executed, without modifying anything.
+    ///
+    /// Used by `Migrating::migration_status`. Defaults to reporting the migration as pending,
+    /// named after its type, with no estimate of the documents it would affect.
+    async fn status<'c>(&self, _coll: &'c Collection<T>) -> Result<MigrationStatus>
+    where
+        T: Sync,
+    {
+        Ok(MigrationStatus {
+            name: std::any::type_name::<Self>().to_string(),
+            pending: true,
+            estimated_count: None,
+        })
+    }
+}
+
+/// A report describing the outcome of executing a migration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// The name of the migration.
+    pub name: String,
+    /// The number of documents matched by the migration's filter.
+    pub matched_count: u64,
+    /// The number of documents modified by the migration.
+    pub modified_count: u64,
+    /// Whether the migration was skipped, such as when its threshold has passed.
+    pub skipped: bool,
+}
+
+impl MigrationReport {
+    /// Construct a report for a migration which was skipped.
+    pub fn skipped(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            matched_count: 0,
+            modified_count: 0,
+            skipped: true,
+        }
+    }
+}
+
+/// A report describing whether a migration would be executed, as given by `Migration::status`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStatus {
+    /// The name of the migration.
+    pub name: String,
+    /// Whether the migration would be executed, rather than skipped, such as when its threshold
+    /// has passed.
+    pub pending: bool,
+    /// The number of documents the migration would affect, if known. Only estimated for pending
+    /// migrations, by counting the documents matched by the migration's filter.
+    pub estimated_count: Option<u64>,
 }
 
 /// A migration type which allows execution until the specifed `threshold` date. Then will no-op.
@@ -57,19 +188,115 @@ pub struct IntervalMigration {
     pub set: Option<Document>,
     /// The document to be used for the `$unset` operation of the update.
     pub unset: Option<Document>,
+    /// The maximum number of documents to update at a time.
+    ///
+    /// When set, the documents matched by `filter` are updated in batches of this size, ordered
+    /// by `_id`, yielding between batches. This keeps any single update bounded, which is useful
+    /// when migrating large collections under live traffic. `None` or `0` disables batching.
+    pub batch_size: Option<u64>,
+    /// The write concern to use for this migration's update; defaults to the model's write
+    /// concern, or to journaled writes acknowledged by a single node, when `None`.
+    pub write_concern: Option<options::WriteConcern>,
+}
+
+/// A builder of `IntervalMigration`s, as returned by `IntervalMigration::builder`.
+#[derive(Default)]
+pub struct IntervalMigrationBuilder {
+    name: Option<String>,
+    threshold: Option<chrono::DateTime<chrono::Utc>>,
+    filter: Option<Document>,
+    set: Option<Document>,
+    unset: Option<Document>,
+    batch_size: Option<u64>,
+    write_concern: Option<options::WriteConcern>,
+}
+
+impl IntervalMigrationBuilder {
+    /// Set the migration's name. Required.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the UTC datetime when the migration should no longer execute. Required.
+    pub fn threshold(mut self, threshold: chrono::DateTime<chrono::Utc>) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    /// Set the filter used for selecting the documents to update. Defaults to all documents.
+    pub fn filter(mut self, filter: Document) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Set the document to be used for the `$set` operation of the update.
+    pub fn set(mut self, set: Document) -> Self {
+        self.set = Some(set);
+        self
+    }
+
+    /// Set the document to be used for the `$unset` operation of the update.
+    pub fn unset(mut self, unset: Document) -> Self {
+        self.unset = Some(unset);
+        self
+    }
+
+    /// Set the maximum number of documents to update at a time.
+    pub fn batch_size(mut self, batch_size: u64) -> Self {
+        self.batch_size = Some(batch_size);
+        self
+    }
+
+    /// Set the write concern to use for the migration's update.
+    pub fn write_concern(mut self, write_concern: options::WriteConcern) -> Self {
+        self.write_concern = Some(write_concern);
+        self
+    }
+
+    /// Build the migration.
+    ///
+    /// Returns `WitherError::MigrationFieldRequired` if the name or threshold is missing, and
+    /// `WitherError::MigrationSetOrUnsetRequired` if neither `set` nor `unset` was given.
+    pub fn build(self) -> Result<IntervalMigration> {
+        let name = self.name.ok_or(WitherError::MigrationFieldRequired("name"))?;
+        let threshold = self.threshold.ok_or(WitherError::MigrationFieldRequired("threshold"))?;
+        if self.set.is_none() && self.unset.is_none() {
+            return Err(WitherError::MigrationSetOrUnsetRequired);
+        }
+        Ok(IntervalMigration {
+            name,
+            threshold,
+            filter: self.filter.unwrap_or_default(),
+            set: self.set,
+            unset: self.unset,
+            batch_size: self.batch_size,
+            write_concern: self.write_concern,
+        })
+    }
 }
 
 #[async_trait]
 impl<T: Sync> Migration<T> for IntervalMigration {
-    async fn execute<'c>(&self, coll: &'c Collection<T>) -> Result<()> {
-        let ns = coll.namespace();
-        log::info!("Executing migration '{}' against '{}'.", &self.name, ns);
-
-        // If the migrations threshold has been passed, then no-op.
-        if chrono::Utc::now() > self.threshold {
-            log::info!("Successfully executed migration '{}' against '{}'. No-op.", &self.name, ns);
-            return Ok(());
-        };
+    async fn execute<'c>(&self, coll: &'c Collection<T>) -> Result<MigrationReport> {
+        self.apply(coll, false).await
+    }
+
+    async fn execute_forced<'c>(&self, coll: &'c Collection<T>) -> Result<MigrationReport> {
+        self.apply(coll, true).await
+    }
+
+    async fn status<'c>(&self, coll: &'c Collection<T>) -> Result<MigrationStatus> {
+        threshold_status(coll, &self.name, self.threshold, self.filter.clone()).await
+    }
+}
+
+impl IntervalMigration {
+    /// Execute this migration, skipping it if its threshold has passed, unless forced.
+    async fn apply<T: Sync>(&self, coll: &Collection<T>, force: bool) -> Result<MigrationReport> {
+        if let Some(report) = skip_past_threshold(&self.name, self.threshold, force, &coll.namespace()) {
+            return Ok(report);
+        }
 
         // Build update document.
         let mut update = doc! {};
@@ -84,23 +311,348 @@ impl<T: Sync> Migration<T> for IntervalMigration {
         }
 
         // Build up & execute the migration.
-        let options = options::UpdateOptions::builder()
-            .upsert(Some(false))
-            .write_concern(Some(
-                options::WriteConcern::builder()
-                    .w(Some(options::Acknowledgment::Majority))
-                    .journal(Some(true))
-                    .build(),
-            ))
+        match self.batch_size.filter(|size| *size > 0) {
+            Some(batch_size) => {
+                let options = migration_update_options(&self.write_concern, coll);
+                let (matched_count, modified_count) = self.execute_batched(coll, update, options, batch_size).await?;
+                Ok(executed_report(&self.name, &coll.namespace(), matched_count, modified_count))
+            }
+            None => update_and_report(coll, &self.name, &self.write_concern, self.filter.clone(), update).await,
+        }
+    }
+
+    /// Get a builder for constructing an `IntervalMigration`.
+    ///
+    /// ```
+    /// # use chrono::TimeZone;
+    /// # use wither::bson::doc;
+    /// # use wither::IntervalMigration;
+    /// let migration = IntervalMigration::builder()
+    ///     .name("remove-oldfield")
+    ///     .threshold(chrono::Utc.ymd(2100, 1, 1).and_hms(1, 0, 0))
+    ///     .filter(doc!{"oldfield": doc!{"$exists": true}})
+    ///     .unset(doc!{"oldfield": ""})
+    ///     .build()
+    ///     .expect("migration should be valid");
+    /// assert!(migration.set.is_none());
+    /// ```
+    pub fn builder() -> IntervalMigrationBuilder {
+        IntervalMigrationBuilder::default()
+    }
+
+    /// Apply the given update to the documents matching this migration's filter, in batches of
+    /// `batch_size` documents ordered by `_id`.
+    ///
+    /// Returns the total matched & modified counts.
+    async fn execute_batched<T: Sync>(
+        &self, coll: &Collection<T>, update: Document, options: options::UpdateOptions, batch_size: u64,
+    ) -> Result<(u64, u64)> {
+        let id_coll = coll.clone_with_type::<Document>();
+        let find_options = options::FindOptions::builder()
+            .projection(Some(doc! {"_id": 1}))
+            .sort(Some(doc! {"_id": 1}))
+            .limit(Some(batch_size as i64))
             .build();
-        let res = coll.update_many(self.filter.clone(), update, Some(options)).await?;
-        log::info!(
-            "Successfully executed migration '{}' against '{}'. {} matched. {} modified.",
-            &self.name,
-            ns,
-            res.matched_count,
-            res.modified_count
-        );
-        Ok(())
+        let (mut matched_count, mut modified_count) = (0, 0);
+        let mut last_id: Option<Bson> = None;
+        loop {
+            // Select the next batch of IDs following the last ID seen.
+            let filter = match &last_id {
+                Some(last_id) => doc! {"$and": [self.filter.clone(), {"_id": {"$gt": last_id.clone()}}]},
+                None => self.filter.clone(),
+            };
+            let docs: Vec<Document> = id_coll
+                .find(filter, Some(find_options.clone()))
+                .await?
+                .try_collect()
+                .await?;
+            let ids: Vec<Bson> = docs.into_iter().filter_map(|mut doc| doc.remove("_id")).collect();
+            let last = match ids.last() {
+                Some(last) => last.clone(),
+                None => break,
+            };
+            let exhausted = (ids.len() as u64) < batch_size;
+
+            // Apply the update to the batch.
+            let filter = doc! {"$and": [self.filter.clone(), {"_id": {"$in": ids}}]};
+            let res = coll.update_many(filter, update.clone(), Some(options.clone())).await?;
+            matched_count += res.matched_count;
+            modified_count += res.modified_count;
+            debug!(
+                "Migration '{}' batch complete. {} matched. {} modified.",
+                &self.name, res.matched_count, res.modified_count
+            );
+            if exhausted {
+                break;
+            }
+            last_id = Some(last);
+            YieldNow(false).await;
+        }
+        Ok((matched_count, modified_count))
+    }
+}
+
+/// A migration type which executes an aggregation pipeline update until the specifed `threshold`
+/// date. Then will no-op.
+///
+/// This migration type behaves exactly as an `IntervalMigration`, except that the update is
+/// expressed as an aggregation pipeline, which allows for fields to be derived from other fields
+/// of the document. Requires MongoDB 4.2+.
+pub struct PipelineMigration {
+    /// The name for this migration. Must be unique per collection.
+    pub name: String,
+    /// The UTC datetime when this migration should no longer execute.
+    ///
+    /// Use something like: `chrono::Utc.ymd(2017, 11, 20).and_hms(22, 37, 34)`.
+    pub threshold: chrono::DateTime<chrono::Utc>,
+    /// The filter to be used for selecting the documents to update.
+    pub filter: Document,
+    /// The aggregation pipeline to be used as the update.
+    pub pipeline: Vec<Document>,
+    /// The write concern to use for this migration's update; defaults to the model's write
+    /// concern, or to journaled writes acknowledged by a single node, when `None`.
+    pub write_concern: Option<options::WriteConcern>,
+}
+
+#[async_trait]
+impl<T: Sync> Migration<T> for PipelineMigration {
+    async fn execute<'c>(&self, coll: &'c Collection<T>) -> Result<MigrationReport> {
+        self.apply(coll, false).await
+    }
+
+    async fn execute_forced<'c>(&self, coll: &'c Collection<T>) -> Result<MigrationReport> {
+        self.apply(coll, true).await
+    }
+
+    async fn status<'c>(&self, coll: &'c Collection<T>) -> Result<MigrationStatus> {
+        threshold_status(coll, &self.name, self.threshold, self.filter.clone()).await
+    }
+}
+
+impl PipelineMigration {
+    /// Execute this migration, skipping it if its threshold has passed, unless forced.
+    async fn apply<T: Sync>(&self, coll: &Collection<T>, force: bool) -> Result<MigrationReport> {
+        if let Some(report) = skip_past_threshold(&self.name, self.threshold, force, &coll.namespace()) {
+            return Ok(report);
+        }
+
+        if self.pipeline.is_empty() {
+            return Err(WitherError::MigrationPipelineRequired);
+        }
+
+        let update = options::UpdateModifications::Pipeline(self.pipeline.clone());
+        update_and_report(coll, &self.name, &self.write_concern, self.filter.clone(), update).await
+    }
+}
+
+/// A migration type which renames a field until the specifed `threshold` date. Then will no-op.
+///
+/// This migration type behaves exactly as an `IntervalMigration`, except that the update is a
+/// `$rename` of the `from` field to the `to` field, which is atomic per document, unlike a `$set`
+/// of the new field followed by an `$unset` of the old one. Only documents matching `filter` which
+/// still have the `from` field are updated, so the migration remains idempotent.
+pub struct RenameMigration {
+    /// The name for this migration. Must be unique per collection.
+    pub name: String,
+    /// The UTC datetime when this migration should no longer execute.
+    ///
+    /// Use something like: `chrono::Utc.ymd(2017, 11, 20).and_hms(22, 37, 34)`.
+    pub threshold: chrono::DateTime<chrono::Utc>,
+    /// The filter to be used for selecting the documents to update.
+    pub filter: Document,
+    /// The field to be renamed. May be a dotted path to a field of an embedded document.
+    pub from: String,
+    /// The new name of the field. May be a dotted path to a field of an embedded document.
+    pub to: String,
+    /// The write concern to use for this migration's update; defaults to the model's write
+    /// concern, or to journaled writes acknowledged by a single node, when `None`.
+    pub write_concern: Option<options::WriteConcern>,
+}
+
+#[async_trait]
+impl<T: Sync> Migration<T> for RenameMigration {
+    async fn execute<'c>(&self, coll: &'c Collection<T>) -> Result<MigrationReport> {
+        self.apply(coll, false).await
+    }
+
+    async fn execute_forced<'c>(&self, coll: &'c Collection<T>) -> Result<MigrationReport> {
+        self.apply(coll, true).await
+    }
+
+    async fn status<'c>(&self, coll: &'c Collection<T>) -> Result<MigrationStatus> {
+        threshold_status(coll, &self.name, self.threshold, self.rename_filter()).await
+    }
+}
+
+impl RenameMigration {
+    /// The filter selecting the documents to update: those matching `filter` which still have
+    /// the `from` field.
+    fn rename_filter(&self) -> Document {
+        doc! {"$and": [self.filter.clone(), {self.from.as_str(): {"$exists": true}}]}
+    }
+
+    /// Execute this migration, skipping it if its threshold has passed, unless forced.
+    async fn apply<T: Sync>(&self, coll: &Collection<T>, force: bool) -> Result<MigrationReport> {
+        if let Some(report) = skip_past_threshold(&self.name, self.threshold, force, &coll.namespace()) {
+            return Ok(report);
+        }
+
+        if self.from.is_empty() || self.to.is_empty() || self.from == self.to {
+            return Err(WitherError::MigrationRenameInvalid);
+        }
+
+        let update = doc! {"$rename": {self.from.as_str(): self.to.as_str()}};
+        update_and_report(coll, &self.name, &self.write_concern, self.rename_filter(), update).await
+    }
+}
+
+/// Log the start of a migration which is executed until the given threshold, returning a skipped
+/// report if the threshold has passed and the migration is not forced.
+fn skip_past_threshold(name: &str, threshold: chrono::DateTime<chrono::Utc>, force: bool, ns: &Namespace) -> Option<MigrationReport> {
+    info!("Executing migration '{}' against '{}'.", name, ns);
+
+    // If the migrations threshold has been passed, then no-op.
+    if chrono::Utc::now() > threshold {
+        if !force {
+            info!("Successfully executed migration '{}' against '{}'. No-op.", name, ns);
+            return Some(MigrationReport::skipped(name));
+        }
+        info!("Forcing migration '{}' past its threshold.", name);
+    }
+    None
+}
+
+/// Apply the given update to all documents matching the given filter, as the named migration,
+/// reporting the matched & modified counts.
+async fn update_and_report<T: Sync>(
+    coll: &Collection<T>, name: &str, write_concern: &Option<options::WriteConcern>, filter: Document,
+    update: impl Into<options::UpdateModifications>,
+) -> Result<MigrationReport> {
+    let options = migration_update_options(write_concern, coll);
+    let res = coll.update_many(filter, update, Some(options)).await?;
+    Ok(executed_report(name, &coll.namespace(), res.matched_count, res.modified_count))
+}
+
+/// Log the successful execution of the named migration, building its report.
+fn executed_report(name: &str, ns: &Namespace, matched_count: u64, modified_count: u64) -> MigrationReport {
+    info!(
+        "Successfully executed migration '{}' against '{}'. {} matched. {} modified.",
+        name, ns, matched_count, modified_count
+    );
+    MigrationReport {
+        name: name.to_string(),
+        matched_count,
+        modified_count,
+        skipped: false,
+    }
+}
+
+/// Get the options to use for a migration's updates, which never upsert.
+fn migration_update_options<T>(write_concern: &Option<options::WriteConcern>, coll: &Collection<T>) -> options::UpdateOptions {
+    options::UpdateOptions::builder()
+        .upsert(Some(false))
+        .write_concern(Some(migration_write_concern(write_concern, coll.write_concern())))
+        .build()
+}
+
+/// Report the status of a migration which is executed until the given threshold, counting the
+/// documents matched by the given filter if it is pending.
+async fn threshold_status<T>(
+    coll: &Collection<T>, name: &str, threshold: chrono::DateTime<chrono::Utc>, filter: Document,
+) -> Result<MigrationStatus> {
+    let pending = chrono::Utc::now() <= threshold;
+    let estimated_count = match pending {
+        true => Some(coll.count_documents(filter, None).await?),
+        false => None,
+    };
+    Ok(MigrationStatus { name: name.to_string(), pending, estimated_count })
+}
+
+/// Get the write concern to use for a migration.
+///
+/// If no override is given, the write concern of the model's collection is used, falling back to
+/// journaled writes acknowledged by a single node, which standalone instances can satisfy.
+fn migration_write_concern(
+    write_concern: &Option<options::WriteConcern>, coll_write_concern: Option<&options::WriteConcern>,
+) -> options::WriteConcern {
+    write_concern
+        .clone()
+        .or_else(|| coll_write_concern.cloned())
+        .unwrap_or_else(|| {
+            options::WriteConcern::builder()
+                .w(Some(options::Acknowledgment::Nodes(1)))
+                .journal(Some(true))
+                .build()
+        })
+}
+
+/// A future which yields control back to the executor once before completing.
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+        self.0 = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn interval_migration_builder_should_build_valid_migrations() {
+        let threshold = chrono::Utc.ymd(2100, 1, 1).and_hms(1, 0, 0);
+        let migration = IntervalMigration::builder()
+            .name("add-field")
+            .threshold(threshold)
+            .set(doc! {"field": 1})
+            .batch_size(10)
+            .build()
+            .expect("Expected a valid migration.");
+        assert_eq!(migration.name, "add-field");
+        assert_eq!(migration.threshold, threshold);
+        assert_eq!(migration.filter, doc! {});
+        assert_eq!(migration.set, Some(doc! {"field": 1}));
+        assert_eq!(migration.unset, None);
+        assert_eq!(migration.batch_size, Some(10));
+    }
+
+    #[test]
+    fn interval_migration_builder_should_reject_incomplete_migrations() {
+        let threshold = chrono::Utc.ymd(2100, 1, 1).and_hms(1, 0, 0);
+        let err = IntervalMigration::builder()
+            .threshold(threshold)
+            .set(doc! {})
+            .build()
+            .err();
+        assert!(matches!(err, Some(WitherError::MigrationFieldRequired("name"))));
+        let err = IntervalMigration::builder().name("m").set(doc! {}).build().err();
+        assert!(matches!(err, Some(WitherError::MigrationFieldRequired("threshold"))));
+        let err = IntervalMigration::builder().name("m").threshold(threshold).build().err();
+        assert!(matches!(err, Some(WitherError::MigrationSetOrUnsetRequired)));
+    }
+
+    #[test]
+    fn migration_write_concern_should_prefer_override_then_collection_default() {
+        let majority = options::WriteConcern::builder()
+            .w(Some(options::Acknowledgment::Majority))
+            .build();
+        let nodes = options::WriteConcern::builder()
+            .w(Some(options::Acknowledgment::Nodes(2)))
+            .build();
+        assert_eq!(migration_write_concern(&Some(majority.clone()), Some(&nodes)), majority);
+        assert_eq!(migration_write_concern(&None, Some(&nodes)), nodes);
+
+        let fallback = migration_write_concern(&None, None);
+        assert_eq!(fallback.w, Some(options::Acknowledgment::Nodes(1)));
+        assert_eq!(fallback.journal, Some(true));
     }
 }
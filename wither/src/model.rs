@@ -1,22 +1,32 @@
 //! Model related code.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
 
 use async_trait::async_trait;
 use mongodb::bson::oid::ObjectId;
-use mongodb::bson::{doc, from_bson, to_bson};
+use mongodb::bson::{doc, from_bson, from_document, to_bson, to_document};
 use mongodb::bson::{Bson, Document};
 use mongodb::options;
 use mongodb::results::DeleteResult;
-use mongodb::{Collection, Database};
+use mongodb::{ClientSession, Collection, Database, Namespace};
 use serde::{de::DeserializeOwned, Serialize};
 
-use crate::common::IndexModel;
+use crate::cache::CacheProvider;
+use crate::common::{
+    BulkWriteOptions, BulkWriteResult, CappedOptions, ExplainVerbosity, IndexInfo, IndexModel, SaveOutcome, SyncOptions, WriteModel,
+};
 use crate::cursor::ModelCursor;
 use crate::error::{Result, WitherError};
+use crate::logging;
+use crate::validate::Validate;
 
 const MONGO_ID_INDEX_NAME: &str = "_id_";
 const MONGO_DIFF_INDEX_BLACKLIST: [&str; 3] = ["v", "ns", "key"];
+/// The delay before the first retry of `save_with_retry`, doubled after each failed attempt.
+const SAVE_RETRY_INITIAL_BACKOFF: std::time::Duration = std::time::Duration::from_millis(10);
+/// The maximum delay between attempts of `save_with_retry`.
+const SAVE_RETRY_MAX_BACKOFF: std::time::Duration = std::time::Duration::from_secs(1);
 
 /// This trait provides data modeling behaviors for interacting with MongoDB database collections.
 ///
@@ -29,6 +39,7 @@ const MONGO_DIFF_INDEX_BLACKLIST: [&str; 3] = ["v", "ns", "key"];
 /// either derived or manually, will be used for collection interactions.
 #[cfg_attr(feature = "docinclude", doc(include = "../docs/model-derive.md"))]
 #[cfg_attr(feature = "docinclude", doc(include = "../docs/model-sync.md"))]
+#[cfg_attr(feature = "docinclude", doc(include = "../docs/model-cursors.md"))]
 #[cfg_attr(feature = "docinclude", doc(include = "../docs/logging.md"))]
 #[cfg_attr(feature = "docinclude", doc(include = "../docs/underlying-driver.md"))]
 #[async_trait]
@@ -66,9 +77,148 @@ where
         None
     }
 
+    /// The model's default index hint.
+    ///
+    /// When set, this is applied by `find`, `find_one` & the like when the caller's options do not
+    /// specify a hint, which is useful for enforcing index usage on hot paths.
+    fn default_hint() -> Option<options::Hint> {
+        None
+    }
+
+    /// The model's default collation.
+    ///
+    /// When set, this is applied by `find`, `find_one` & the like when the caller's options do not
+    /// specify a collation.
+    fn default_collation() -> Option<options::Collation> {
+        None
+    }
+
+    /// The model's default maximum execution time for queries.
+    ///
+    /// When set, this is applied as the `maxTimeMS` of `find`, `find_one`, `aggregate`, `count`,
+    /// `exists`, `distinct` & the like when the caller's options do not specify a `max_time`,
+    /// which protects against runaway queries.
+    fn default_max_time() -> Option<std::time::Duration> {
+        None
+    }
+
+    /// Whether aggregations on this model should be allowed to write to temporary files.
+    ///
+    /// When `true`, this is applied as the `allowDiskUse` of `aggregate` & `aggregate_one` when
+    /// the caller's options do not specify `allow_disk_use`, which spares heavy analytical
+    /// pipelines from hitting the server's memory limit. When deriving a model, use
+    /// `#[model(allow_disk_use)]`.
+    fn default_allow_disk_use() -> bool {
+        false
+    }
+
+    /// Whether `save` & `update` should force journaled write concern.
+    ///
+    /// Defaults to `true`. When deriving a model, use `#[model(save_force_journal=false)]` to opt
+    /// out, in which case the model's write concern will be used as is.
+    fn save_forces_journal() -> bool {
+        true
+    }
+
+    /// The serialized name of the model's version field, used for optimistic concurrency.
+    ///
+    /// When deriving a model, use `#[model(version_field="version")]` to designate the field. See
+    /// `Model::save_versioned` for details.
+    fn version_field() -> Option<&'static str> {
+        None
+    }
+
+    /// The snapshot of this instance's serialized form, as taken by `Model::mark_clean`, if this
+    /// model declares a snapshot field.
+    ///
+    /// When deriving a model, use `#[model(snapshot_field="snapshot")]` to designate a field of
+    /// type `Option<Document>` marked with `#[serde(skip)]`. See `Model::save_if_dirty` for details.
+    fn snapshot(&self) -> Option<&Option<Document>> {
+        None
+    }
+
+    /// A mutable reference to the snapshot of this instance's serialized form, if this model
+    /// declares a snapshot field. See `Model::snapshot`.
+    fn snapshot_mut(&mut self) -> Option<&mut Option<Document>> {
+        None
+    }
+
+    /// Whether this model is read-only, such as a model backed by a view.
+    ///
+    /// When `true`, methods which write to the model's collection, such as `save`, `update` &
+    /// `delete`, along with `ensure_index`, `drop_indexes` & `drop_collection`, return
+    /// `WitherError::ReadOnlyModel` without making any request to the database.
+    /// When deriving a model, use `#[model(read_only)]` to enable this.
+    fn read_only() -> bool {
+        false
+    }
+
+    /// The parameters of this model's collection, if it is a capped collection.
+    ///
+    /// When set, `sync` creates the collection as a capped collection if it does not yet exist.
+    /// MongoDB can not resize an existing capped collection, so if its `size` or `max` differs
+    /// from the declared values, `sync` logs a warning describing the drift; see
+    /// `SyncOptions::recreate_empty_capped` for recreating empty collections. When deriving a
+    /// model, use `#[model(capped(size=4096, max=1000))]`, where `max` is optional.
+    fn capped() -> Option<CappedOptions> {
+        None
+    }
+
+    /// The server-side validator of this model's collection, such as a `$jsonSchema` document.
+    ///
+    /// When set, `sync` creates the collection with this validator if it does not yet exist, or
+    /// applies it via `collMod` if the collection's current validator, validation level or
+    /// validation action differ from the declared ones. A validator on the collection is left
+    /// untouched when this is `None`. When deriving a model, use
+    /// `#[model(validator=r#"doc!{"$jsonSchema": {...}}"#)]`.
+    fn validator() -> Option<Document> {
+        None
+    }
+
+    /// The validation level to apply along with this model's `validator`.
+    ///
+    /// Defaults to the server's default, `strict`, when `None`. When deriving a model, use
+    /// `#[model(validation_level="moderate")]`.
+    fn validation_level() -> Option<options::ValidationLevel> {
+        None
+    }
+
+    /// The validation action to apply along with this model's `validator`.
+    ///
+    /// Defaults to the server's default, `error`, when `None`. When deriving a model, use
+    /// `#[model(validation_action="warn")]`.
+    fn validation_action() -> Option<options::ValidationAction> {
+        None
+    }
+
+    /// The serialized names of fields which `save` must not overwrite, such as fields maintained
+    /// by another service.
+    ///
+    /// When non-empty, `save` & `save_outcome` issue an upsert which `$set`s all other fields of
+    /// the instance, and only writes these fields via `$setOnInsert`, when a new document is
+    /// inserted. Stored values of these fields are thus preserved. As the document is no longer
+    /// replaced, stored fields which the serialized instance omits are preserved as well. When
+    /// deriving a model, mark such fields with `#[model(no_overwrite)]`.
+    ///
+    /// `save_versioned` preserves these fields in the same way. `replace` & `find_one_and_replace`
+    /// always replace the document entirely, ignoring these fields, while inserts such as
+    /// `save_new` & `save_with_id` write all fields, as for any new document.
+    fn no_overwrite_fields() -> &'static [&'static str] {
+        &[]
+    }
+
     //////////////////////////////////////////////////////////////////////////////////////////////
     // Static Layer //////////////////////////////////////////////////////////////////////////////
 
+    /// Resolve the name of the collection to use for this model.
+    ///
+    /// Defaults to `COLLECTION_NAME`. Override this to compute the collection name at runtime,
+    /// such as from configuration. For data partitioned across multiple collections, EG per
+    /// tenant, see `Model::collection_named` & the various `*_in` methods.
+    fn resolve_collection_name() -> String {
+        Self::COLLECTION_NAME.to_string()
+    }
+
     /// Get a handle to this model's collection.
     ///
     /// If there are any methods available on the underlying driver's collection object which are
@@ -79,23 +229,183 @@ where
     /// This method uses the model's `selection_criteria`, `read_concern` & `write_concern` when
     /// constructing the collection handle.
     fn collection(db: &Database) -> Collection<Self> {
-        db.collection_with_options(
-            Self::COLLECTION_NAME,
-            options::CollectionOptions::builder()
-                .selection_criteria(Self::selection_criteria())
-                .read_concern(Self::read_concern())
-                .write_concern(Self::write_concern())
-                .build(),
-        )
+        Self::collection_named(db, &Self::resolve_collection_name())
+    }
+
+    /// Get a handle to the given collection, for use with this model.
+    ///
+    /// This allows a single model type to be used across multiple physical collections, such as
+    /// one collection per tenant. The model's `selection_criteria`, `read_concern` &
+    /// `write_concern` are used when constructing the collection handle.
+    fn collection_named(db: &Database, name: &str) -> Collection<Self> {
+        collection_with_name(db, name, Default::default())
+    }
+
+    /// Get a handle to this model's collection, using the given collection options.
+    ///
+    /// Any of the `selection_criteria`, `read_concern` & `write_concern` options which are not
+    /// set in the given options will fall back to the model's configured values. This is useful
+    /// for tuning a single query, such as an analytics scan using a secondary read preference.
+    fn collection_with(db: &Database, opts: options::CollectionOptions) -> Collection<Self> {
+        collection_with_name(db, &Self::resolve_collection_name(), opts)
+    }
+
+    /// Get a handle to this model's collection, with its documents typed as `T`.
+    ///
+    /// This behaves exactly as `Model::collection`, including the model's concerns & selection
+    /// criteria, but reinterprets the collection's documents, EG as a raw `Document` or a
+    /// projection type, for driver calls which are not wrapped by the model interface.
+    fn typed_collection<T>(db: &Database) -> Collection<T> {
+        Self::collection(db).clone_with_type::<T>()
+    }
+
+    /// Get the fully-qualified namespace of this model's collection, as `<database>.<collection>`.
+    ///
+    /// This matches the namespace used in the log messages of this crate, and is useful for
+    /// tagging logs & metrics without constructing a collection handle.
+    fn namespace(db: &Database) -> String {
+        format!("{}.{}", db.name(), Self::resolve_collection_name())
     }
 
     /// Find all instances of this model matching the given query.
+    ///
+    /// The filter may be given as `None`, or as a bare `Document`, EG `doc!{"x": 1}`, as `Option`
+    /// implements `From<T>`. Custom query builders may be passed by implementing
+    /// `From<MyBuilder> for Option<Document>`.
     async fn find<F, O>(db: &Database, filter: F, options: O) -> Result<ModelCursor<Self>>
     where
         F: Into<Option<Document>> + Send,
         O: Into<Option<options::FindOptions>> + Send,
     {
-        Ok(Self::collection(db).find(filter, options).await.map(ModelCursor::new)?)
+        Self::find_with_collection_options(db, None, filter, options).await
+    }
+
+    /// Find all instances of this model, in the given sort order, EG `doc!{"_id": -1}` for newest
+    /// first.
+    ///
+    /// Instances are loaded lazily as the returned cursor is consumed, so this is safe to use on
+    /// large collections when the cursor is consumed incrementally, rather than collected.
+    async fn all<S>(db: &Database, sort: S) -> Result<ModelCursor<Self>>
+    where
+        S: Into<Option<Document>> + Send,
+    {
+        let options = options::FindOptions::builder().sort(sort.into()).build();
+        Self::find(db, None, options).await
+    }
+
+    /// Find all instances of this model matching the given query, in the given collection.
+    ///
+    /// See `Model::collection_named` for details.
+    async fn find_in<F, O>(db: &Database, collection_name: &str, filter: F, options: O) -> Result<ModelCursor<Self>>
+    where
+        F: Into<Option<Document>> + Send,
+        O: Into<Option<options::FindOptions>> + Send,
+    {
+        let coll = Self::collection_named(db, collection_name).clone_with_type::<Document>();
+        let options = find_options_with_defaults::<Self>(options.into());
+        Ok(logging::instrument("find", coll.namespace(), coll.find(filter, options))
+            .await
+            .map(ModelCursor::new)?)
+    }
+
+    /// Find all instances of this model matching the given query, using the given pre-built find
+    /// options.
+    ///
+    /// This is the supported path for tuning the behavior of the returned cursor, such as setting
+    /// `batch_size` for large exports or `no_cursor_timeout` for long-running scans. See the
+    /// cursors section of these docs for an example.
+    async fn find_with(db: &Database, filter: Document, options: options::FindOptions) -> Result<ModelCursor<Self>> {
+        Self::find(db, filter, options).await
+    }
+
+    /// Find all instances of this model matching the given query, using the given collection
+    /// options to override the model's defaults for this query.
+    ///
+    /// See `Model::collection_with` for details on how the collection options are applied.
+    async fn find_with_collection_options<F, O>(
+        db: &Database, collection_options: Option<options::CollectionOptions>, filter: F, options: O,
+    ) -> Result<ModelCursor<Self>>
+    where
+        F: Into<Option<Document>> + Send,
+        O: Into<Option<options::FindOptions>> + Send,
+    {
+        let coll = match collection_options {
+            Some(opts) => Self::collection_with(db, opts),
+            None => Self::collection(db),
+        };
+        let options = find_options_with_defaults::<Self>(options.into());
+        let coll = coll.clone_with_type::<Document>();
+        Ok(logging::instrument("find", coll.namespace(), coll.find(filter, options))
+            .await
+            .map(ModelCursor::new)?)
+    }
+
+    /// Open a tailable cursor over the instances of this model matching the given query.
+    ///
+    /// The cursor is created with `CursorType::TailableAwait`, so once the existing documents have
+    /// been returned it waits for new documents to be inserted, rather than being exhausted. This
+    /// only works on capped collections. The cursor never completes on its own, making it
+    /// suitable for an indefinite `while let Some(event) = cursor.try_next().await? {..}` loop;
+    /// it ends only if the server kills it, EG when the collection is dropped. As tailable
+    /// cursors always return documents in insertion order, the model's default hint, collation &
+    /// max time are not applied.
+    async fn tail<F>(db: &Database, filter: F) -> Result<ModelCursor<Self>>
+    where
+        F: Into<Option<Document>> + Send,
+    {
+        let options = options::FindOptions::builder()
+            .cursor_type(Some(options::CursorType::TailableAwait))
+            .build();
+        let coll = Self::typed_collection::<Document>(db);
+        Ok(logging::instrument("tail", coll.namespace(), coll.find(filter, Some(options)))
+            .await
+            .map(ModelCursor::new)?)
+    }
+
+    /// Find all documents of this model matching the given query, deserializing only the
+    /// projected fields into instances of `P`.
+    ///
+    /// The given projection will override any projection present in the given options.
+    async fn find_projected<P, F, O>(db: &Database, filter: F, projection: Document, options: O) -> Result<ModelCursor<P>>
+    where
+        P: DeserializeOwned + Unpin + Send + Sync,
+        F: Into<Option<Document>> + Send,
+        O: Into<Option<options::FindOptions>> + Send,
+    {
+        let mut options = options.into().unwrap_or_default();
+        options.projection = Some(projection);
+        let coll = Self::typed_collection::<Document>(db);
+        let options = find_options_with_defaults::<Self>(Some(options));
+        Ok(logging::instrument("find", coll.namespace(), coll.find(filter, options))
+            .await
+            .map(ModelCursor::new)?)
+    }
+
+    /// Explain how the server would execute `Model::find` with the given query & options,
+    /// returning the raw output of the `explain` command.
+    ///
+    /// The model's default hint, collation & max time, and its selection criteria, are applied
+    /// exactly as for `find`, so the plan reflects the query the model would actually run. With
+    /// `ExplainVerbosity::ExecutionStats` or above, the query is executed to gather statistics,
+    /// though no documents are returned. Any read concern in the given options is ignored, as the
+    /// `explain` command does not accept one.
+    async fn explain_find<F, O>(db: &Database, filter: F, options: O, verbosity: ExplainVerbosity) -> Result<Document>
+    where
+        F: Into<Option<Document>> + Send,
+        O: Into<Option<options::FindOptions>> + Send,
+    {
+        let coll = Self::collection(db);
+        let options = find_options_with_defaults::<Self>(options.into()).unwrap_or_default();
+        let selection_criteria = options.selection_criteria.clone().or_else(Self::selection_criteria);
+        let mut find = doc! {"find": coll.name()};
+        if let Some(filter) = filter.into() {
+            find.insert("filter", filter);
+        }
+        let mut options = to_document(&options)?;
+        options.remove("readConcern");
+        find.extend(options);
+        let command = doc! {"explain": find, "verbosity": verbosity.as_str()};
+        Ok(logging::instrument("explain_find", coll.namespace(), db.run_command(command, selection_criteria)).await?)
     }
 
     /// Find the one model record matching your query, returning a model instance.
@@ -104,7 +414,216 @@ where
         F: Into<Option<Document>> + Send,
         O: Into<Option<options::FindOneOptions>> + Send,
     {
-        Ok(Self::collection(db).find_one(filter, options).await?)
+        let coll = Self::collection(db);
+        let options = find_one_options_with_defaults::<Self>(options.into());
+        Ok(logging::instrument("find_one", coll.namespace(), coll.find_one(filter, options)).await?)
+    }
+
+    /// Find the instance of this model with the given ID, reading through the given cache.
+    ///
+    /// The cache is checked first, under the key `<namespace>:<id>`. On a miss, the instance is
+    /// loaded via `find_one` and, if found, written to the cache. Invalidation is left to the
+    /// caller, so the cached form of an instance may be stale after it is saved or deleted.
+    async fn find_by_id_cached<C>(db: &Database, cache: &C, id: &ObjectId) -> Result<Option<Self>>
+    where
+        C: CacheProvider + ?Sized,
+    {
+        let key = format!("{}:{}", Self::namespace(db), id);
+        if let Some(document) = cache.get(&key).await? {
+            return Ok(Some(Self::instance_from_document(document)?));
+        }
+        let model = match Self::find_one(db, doc! {"_id": id}, None).await? {
+            Some(model) => model,
+            None => return Ok(None),
+        };
+        cache.set(&key, model.document_from_instance()?).await?;
+        Ok(Some(model))
+    }
+
+    /// Find the one model record matching your query, in the given collection, returning a model
+    /// instance.
+    ///
+    /// See `Model::collection_named` for details.
+    async fn find_one_in<F, O>(db: &Database, collection_name: &str, filter: F, options: O) -> Result<Option<Self>>
+    where
+        F: Into<Option<Document>> + Send,
+        O: Into<Option<options::FindOneOptions>> + Send,
+    {
+        let coll = Self::collection_named(db, collection_name);
+        let options = find_one_options_with_defaults::<Self>(options.into());
+        Ok(logging::instrument("find_one", coll.namespace(), coll.find_one(filter, options)).await?)
+    }
+
+    /// Find the one model record matching your query, persisting any serde defaults which were
+    /// applied while deserializing it.
+    ///
+    /// This supports lazy, read-triggered schema upgrades: when a field with `#[serde(default)]`
+    /// is missing from the stored document, the defaulted value is written back with a `$set` of
+    /// only the missing fields, targeting the document by its `_id`. Documents which needed no
+    /// defaults are returned without any write. See `Model::instance_from_document_defaulted` for
+    /// how defaulted fields are detected.
+    async fn find_one_and_upgrade<F, O>(db: &Database, filter: F, options: O) -> Result<Option<Self>>
+    where
+        F: Into<Option<Document>> + Send,
+        O: Into<Option<options::FindOneOptions>> + Send,
+    {
+        ensure_writable::<Self>()?;
+        let coll = Self::collection(db).clone_with_type::<Document>();
+        let options = find_one_options_with_defaults::<Self>(options.into());
+        let document = match logging::instrument("find_one", coll.namespace(), coll.find_one(filter, options)).await? {
+            Some(document) => document,
+            None => return Ok(None),
+        };
+        let id = document.get("_id").cloned();
+        let original = document.clone();
+        let model = Self::instance_from_document(document)?;
+        let missing = missing_fields(&original, &model.document_from_instance()?);
+        if let (Some(id), false) = (id, missing.is_empty()) {
+            logging::instrument(
+                "update_one",
+                coll.namespace(),
+                coll.update_one(doc! {"_id": id}, doc! {"$set": missing}, None),
+            )
+            .await?;
+        }
+        Ok(Some(model))
+    }
+
+    /// Count the documents in this model's collection which match the given filter.
+    ///
+    /// A terse form of the driver's `Collection.count_documents`, useful for checking the
+    /// selectivity of a filter, such as one used by a migration.
+    async fn count(db: &Database, filter: Document) -> Result<u64> {
+        let coll = Self::collection(db);
+        let options = count_options_with_defaults::<Self>(None);
+        Ok(logging::instrument("count", coll.namespace(), coll.count_documents(filter, options)).await?)
+    }
+
+    /// Check if any document in this model's collection matches the given filter.
+    ///
+    /// This issues a `count_documents` limited to a single document, so no documents are
+    /// transferred from the server or deserialized.
+    async fn exists(db: &Database, filter: Document) -> Result<bool> {
+        let coll = Self::collection(db);
+        let options = count_options_with_defaults::<Self>(Some(options::CountOptions::builder().limit(Some(1)).build()));
+        Ok(logging::instrument("exists", coll.namespace(), coll.count_documents(filter, options)).await? > 0)
+    }
+
+    /// Find the distinct values of the given field across this model's collection.
+    ///
+    /// Each of the distinct values returned from the server will be deserialized into an instance
+    /// of `V`. Any value which fails to deserialize will cause this method to return an error.
+    async fn distinct<V, F, O>(db: &Database, field_name: &str, filter: F, options: O) -> Result<Vec<V>>
+    where
+        V: DeserializeOwned,
+        F: Into<Option<Document>> + Send,
+        O: Into<Option<options::DistinctOptions>> + Send,
+    {
+        let mut options = options.into();
+        if let Some(max_time) = Self::default_max_time() {
+            let options = options.get_or_insert_with(Default::default);
+            options.max_time = options.max_time.or(Some(max_time));
+        }
+        let coll = Self::collection(db);
+        let values = logging::instrument("distinct", coll.namespace(), coll.distinct(field_name, filter, options)).await?;
+        Ok(values
+            .into_iter()
+            .map(from_bson::<V>)
+            .collect::<std::result::Result<Vec<V>, _>>()?)
+    }
+
+    /// Count the documents matching the given filter, grouped by the value of the given field.
+    ///
+    /// This runs a `$group` aggregation on the field, returning a map of each value to the number
+    /// of documents holding it. String values are used as is, while all other values are
+    /// stringified via their BSON display, EG `null` for documents missing the field. Values
+    /// which stringify identically, such as the string `"1"` & the integer `1`, share a count.
+    async fn count_by<F>(db: &Database, field: &str, filter: F) -> Result<HashMap<String, u64>>
+    where
+        F: Into<Option<Document>> + Send,
+    {
+        let mut pipeline = vec![];
+        if let Some(filter) = filter.into() {
+            pipeline.push(doc! {"$match": filter});
+        }
+        pipeline.push(doc! {"$group": {"_id": format!("${}", field), "count": {"$sum": 1}}});
+        let mut cursor = Self::aggregate::<Document, _>(db, pipeline, None).await?;
+        let mut counts = HashMap::new();
+        while let Some(group) = cursor.try_next().await? {
+            let key = match group.get("_id") {
+                Some(Bson::String(val)) => val.clone(),
+                Some(val) => val.to_string(),
+                None => Bson::Null.to_string(),
+            };
+            *counts.entry(key).or_insert(0) += response_count(&group, "count");
+        }
+        Ok(counts)
+    }
+
+    /// Execute the given aggregation pipeline against this model's collection, deserializing each
+    /// of the resulting documents into an instance of `R`.
+    async fn aggregate<R, O>(db: &Database, pipeline: Vec<Document>, options: O) -> Result<ModelCursor<R>>
+    where
+        R: DeserializeOwned + Unpin + Send + Sync,
+        O: Into<Option<options::AggregateOptions>> + Send,
+    {
+        Self::aggregate_with_collection_options(db, None, pipeline, options).await
+    }
+
+    /// Execute the given aggregation pipeline against this model's collection, deserializing only
+    /// the first resulting document into an instance of `R`.
+    ///
+    /// This is useful for pipelines which produce a single document, such as a `$group` without
+    /// a grouping key, or a `$facet`. Returns `None` if the pipeline produced no documents.
+    async fn aggregate_one<R, O>(db: &Database, pipeline: Vec<Document>, options: O) -> Result<Option<R>>
+    where
+        R: DeserializeOwned + Unpin + Send + Sync,
+        O: Into<Option<options::AggregateOptions>> + Send,
+    {
+        Self::aggregate::<R, O>(db, pipeline, options).await?.try_next().await
+    }
+
+    /// Execute the given aggregation pipeline against this model's collection for its side
+    /// effects only, such as a pipeline ending in a `$merge` or `$out` stage.
+    ///
+    /// Such pipelines produce no documents, so no output type is needed; any documents which are
+    /// returned are drained & discarded. The options are defaulted exactly as for `aggregate`.
+    async fn run_pipeline<O>(db: &Database, pipeline: Vec<Document>, options: O) -> Result<()>
+    where
+        O: Into<Option<options::AggregateOptions>> + Send,
+    {
+        let mut cursor = Self::aggregate::<Document, O>(db, pipeline, options).await?;
+        while cursor.try_next().await?.is_some() {}
+        Ok(())
+    }
+
+    /// Execute the given aggregation pipeline against this model's collection, using the given
+    /// collection options to override the model's defaults for this pipeline.
+    ///
+    /// See `Model::collection_with` for details on how the collection options are applied.
+    async fn aggregate_with_collection_options<R, O>(
+        db: &Database, collection_options: Option<options::CollectionOptions>, pipeline: Vec<Document>, options: O,
+    ) -> Result<ModelCursor<R>>
+    where
+        R: DeserializeOwned + Unpin + Send + Sync,
+        O: Into<Option<options::AggregateOptions>> + Send,
+    {
+        let coll = match collection_options {
+            Some(opts) => Self::collection_with(db, opts),
+            None => Self::collection(db),
+        };
+        let mut options = options.into();
+        if let Some(max_time) = Self::default_max_time() {
+            let options = options.get_or_insert_with(Default::default);
+            options.max_time = options.max_time.or(Some(max_time));
+        }
+        if Self::default_allow_disk_use() {
+            let options = options.get_or_insert_with(Default::default);
+            options.allow_disk_use = options.allow_disk_use.or(Some(true));
+        }
+        Ok(logging::instrument("aggregate", coll.namespace(), coll.aggregate(pipeline, options))
+            .await
+            .map(ModelCursor::new)?)
     }
 
     /// Finds a single document and deletes it, returning the original.
@@ -112,7 +631,35 @@ where
     where
         O: Into<Option<options::FindOneAndDeleteOptions>> + Send,
     {
-        Ok(Self::collection(db).find_one_and_delete(filter, options).await?)
+        ensure_writable::<Self>()?;
+        let coll = Self::collection(db);
+        Ok(logging::instrument("find_one_and_delete", coll.namespace(), coll.find_one_and_delete(filter, options)).await?)
+    }
+
+    /// Finds all documents matching the given filter and deletes them, returning the originals.
+    ///
+    /// **NOTE WELL:** MongoDB has no single command for this, so it is performed as two separate
+    /// operations, and is not atomic. All matching documents are first found, then a
+    /// `delete_many` is issued targeting only the `_id`s of the documents found. Documents which
+    /// begin to match the filter between the two operations are not deleted, while documents
+    /// which were found but changed before being deleted are still deleted. Only documents with
+    /// an ID are deleted & returned.
+    async fn find_and_delete_many(db: &Database, filter: Document) -> Result<Vec<Self>> {
+        ensure_writable::<Self>()?;
+        let mut cursor = Self::find(db, filter, None).await?;
+        let mut models = vec![];
+        while let Some(model) = cursor.try_next().await? {
+            if model.id().is_some() {
+                models.push(model);
+            }
+        }
+        if models.is_empty() {
+            return Ok(models);
+        }
+        let ids: Vec<_> = models.iter().filter_map(|model| model.id()).collect();
+        let coll = Self::collection(db);
+        logging::instrument("delete_many", coll.namespace(), coll.delete_many(doc! {"_id": {"$in": ids}}, None)).await?;
+        Ok(models)
     }
 
     /// Finds a single document and replaces it, returning either the original or replaced document.
@@ -120,9 +667,14 @@ where
     where
         O: Into<Option<options::FindOneAndReplaceOptions>> + Send,
     {
-        Ok(Self::collection(db)
-            .find_one_and_replace(filter, replacement, options)
-            .await?)
+        ensure_writable::<Self>()?;
+        let coll = Self::collection(db);
+        Ok(logging::instrument(
+            "find_one_and_replace",
+            coll.namespace(),
+            coll.find_one_and_replace(filter, replacement, options),
+        )
+        .await?)
     }
 
     /// Finds a single document and updates it, returning either the original or updated document.
@@ -131,7 +683,70 @@ where
         U: Into<options::UpdateModifications> + Send,
         O: Into<Option<options::FindOneAndUpdateOptions>> + Send,
     {
-        Ok(Self::collection(db).find_one_and_update(filter, update, options).await?)
+        ensure_writable::<Self>()?;
+        let coll = Self::collection(db);
+        Ok(logging::instrument("find_one_and_update", coll.namespace(), coll.find_one_and_update(filter, update, options)).await?)
+    }
+
+    /// Finds the document with the given ID and updates it, returning either the original or
+    /// updated document, per the given options.
+    ///
+    /// Unlike `Model::update`, this does not require an instance of the model. Returns `None` if
+    /// no document has the given ID.
+    async fn update_by_id<U, O>(db: &Database, id: &ObjectId, update: U, options: O) -> Result<Option<Self>>
+    where
+        U: Into<options::UpdateModifications> + Send,
+        O: Into<Option<options::FindOneAndUpdateOptions>> + Send,
+    {
+        Self::find_one_and_update(db, doc! {"_id": id}, update, options).await
+    }
+
+    /// Execute the given write models against this model's collection as a bulk write.
+    ///
+    /// Consecutive write models of the same kind are batched together into a single `insert`,
+    /// `update` or `delete` command. If no write concern is specified in the given options, the
+    /// model's write concern will be used.
+    ///
+    /// Any write errors reported by the server are returned as a `BulkWrite` error from the
+    /// underlying driver. For ordered bulk writes, no further batches are executed after the first
+    /// batch reporting write errors.
+    async fn bulk_write<O>(db: &Database, ops: Vec<WriteModel>, options: O) -> Result<BulkWriteResult>
+    where
+        O: Into<Option<BulkWriteOptions>> + Send,
+    {
+        ensure_writable::<Self>()?;
+        let mut options = options.into().unwrap_or_default();
+        if options.write_concern.is_none() {
+            options.write_concern = Self::write_concern();
+        }
+        let coll = Self::collection(db);
+        logging::instrument("bulk_write", coll.namespace(), bulk_write(db, &coll, ops, options)).await
+    }
+
+    /// Upsert the given model instances, each replacing the first document matching its filter.
+    ///
+    /// This is issued as a single ordered `bulk_write` of `WriteModel::ReplaceOne` write models
+    /// with `upsert` enabled, using the model's write concern. It is well suited for idempotently
+    /// seeding reference data, where each instance is matched by a business key.
+    ///
+    /// Instances are serialized via `Model::document_from_instance`. Instances without an ID will
+    /// have one generated by the server when inserted, which is not set on the given instances.
+    async fn upsert_many(db: &Database, items: Vec<(Document, Self)>) -> Result<()> {
+        if items.is_empty() {
+            return Ok(());
+        }
+        let ops = items
+            .into_iter()
+            .map(|(filter, instance)| {
+                Ok(WriteModel::ReplaceOne {
+                    filter,
+                    replacement: instance.document_from_instance()?,
+                    upsert: true,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::bulk_write(db, ops, None).await?;
+        Ok(())
     }
 
     //////////////////////////////////////////////////////////////////////////////////////////////
@@ -148,175 +763,1096 @@ where
     /// If a `filter` is specified, and no ID exists for the instance, then the filter will be used
     /// and the first document matching the filter will be replaced by this instance. This is
     /// useful when the model has unique indexes on fields which need to be the target of the save
-    /// operation.
+    /// operation. Only the `_id` of the saved document is returned by the server, so the document
+    /// itself is not sent back over the wire.
     ///
     /// **NOTE WELL:** in order to ensure needed behavior of this method, it will force `journaled`
-    /// write concern.
+    /// write concern, unless `Model::save_forces_journal` returns `false`.
     async fn save(&mut self, db: &Database, filter: Option<Document>) -> Result<()> {
-        let coll = Self::collection(db);
-
-        // Ensure that journaling is set to true for this call, as we need to be able to get an ID back.
-        let mut write_concern = Self::write_concern().unwrap_or_default();
-        write_concern.journal = Some(true);
-
-        // Handle case where instance already has an ID.
-        let mut id_needs_update = false;
-        let filter = match (self.id(), filter) {
-            (Some(id), _) => doc! {"_id": id},
-            (None, None) => {
-                let new_id = ObjectId::new();
-                self.set_id(new_id);
-                doc! {"_id": new_id}
-            }
-            (None, Some(filter)) => {
-                id_needs_update = true;
-                filter
-            }
-        };
+        save_model(self, db, &Self::resolve_collection_name(), None, filter).await
+    }
 
-        // Save the record by replacing it entirely, or upserting if it doesn't already exist.
-        let opts = options::FindOneAndReplaceOptions::builder()
-            .upsert(Some(true))
-            .write_concern(Some(write_concern))
-            .return_document(Some(options::ReturnDocument::After))
-            .build();
-        let updated_doc = coll
-            .find_one_and_replace(filter, &(*self), Some(opts))
-            .await?
-            .ok_or(WitherError::ServerFailedToReturnUpdatedDoc)?;
-        let updated_doc = Self::document_from_instance(&updated_doc)?;
-
-        // Update instance ID if needed.
-        if id_needs_update {
-            let response_id = updated_doc
-                .get_object_id("_id")
-                .map_err(|_| WitherError::ServerFailedToReturnObjectId)?;
-            self.set_id(response_id);
-        };
+    /// Save the current model instance, which has no ID yet, using the given ID.
+    ///
+    /// This behaves exactly as `Model::save_new`, but rather than generating an ID for the new
+    /// document, the given ID is used, EG one built via `ObjectId::from_bytes` to encode a
+    /// historical timestamp during a backfill, so that range queries on `_id` reflect creation
+    /// times. As a plain insert is used, an existing document with the given ID is never
+    /// overwritten; a duplicate key error is returned instead, see `WitherError::is_duplicate_key`.
+    /// The ID is set on this instance once the insert succeeds. If the instance already has an
+    /// ID, `WitherError::ModelIdAlreadySet` is returned.
+    async fn save_with_id(&mut self, db: &Database, id: ObjectId) -> Result<()> {
+        ensure_writable::<Self>()?;
+        if self.id().is_some() {
+            return Err(WitherError::ModelIdAlreadySet);
+        }
+        let mut document = self.document_from_instance()?;
+        document.insert("_id", id);
+        let coll = Self::typed_collection::<Document>(db);
+        logging::instrument("insert_one", coll.namespace(), coll.insert_one(document, None)).await?;
+        self.set_id(id);
         Ok(())
     }
 
-    /// Update the current model instance.
+    /// Save the current model instance, reporting whether a new document was inserted.
     ///
-    /// This operation will always target the model instance by the instance's ID. If its ID is
-    /// `None`, this method will return an error. If a filter document is provided, this method
-    /// will ensure that the key `_id` is set to this model's ID.
+    /// This behaves exactly as `Model::save`, but returns a `SaveOutcome` describing whether the
+    /// save inserted a new document or replaced an existing one, along with the document's ID.
+    /// The outcome is taken from the server's `findAndModify` response, so it is accurate even
+    /// when the instance already had an ID which was not yet present in the collection.
+    async fn save_outcome(&mut self, db: &Database, filter: Option<Document>) -> Result<SaveOutcome> {
+        save_model_outcome(self, db, &Self::resolve_collection_name(), filter).await
+    }
+
+    /// Save the current model instance to the given collection.
     ///
-    /// This method will consume `self`, and will return a new instance of `Self` based on the given
-    /// return options (`ReturnDocument::Before | ReturnDocument:: After`).
+    /// This behaves exactly as `Model::save`. See `Model::collection_named` for details.
+    async fn save_in(&mut self, db: &Database, collection_name: &str, filter: Option<Document>) -> Result<()> {
+        save_model(self, db, collection_name, None, filter).await
+    }
+
+    /// Save the current model instance as part of the given session.
     ///
-    /// In order to provide consistent behavior, this method will also ensure that the operation's
-    /// write concern `journaling` is set to `true`, so that we can receive a complete output
-    /// document.
+    /// This behaves exactly as `Model::save`, but allows the save to participate in a transaction.
+    /// The journaled write concern is applied at the collection level, as the server does not
+    /// permit per-operation write concerns within a transaction; within a transaction, the write
+    /// concern of the transaction itself is used instead.
+    async fn save_with_session(&mut self, db: &Database, session: &mut ClientSession, filter: Option<Document>) -> Result<()> {
+        save_model(self, db, &Self::resolve_collection_name(), Some(session), filter).await
+    }
+
+    /// Validate the current model instance, then save it.
     ///
-    /// If this model instance was never written to the database, this operation will return an
-    /// error.
-    async fn update(self, db: &Database, filter: Option<Document>, update: Document, opts: Option<options::FindOneAndUpdateOptions>) -> Result<Self> {
-        // Extract model's ID & use as filter for this operation.
-        let id = self.id().ok_or(WitherError::ModelIdRequiredForOperation)?;
+    /// If validation fails, the error from `Validate::validate` is returned and no request is
+    /// made to the database. Otherwise this behaves exactly as `Model::save`.
+    async fn save_validated(&mut self, db: &Database, filter: Option<Document>) -> Result<()>
+    where
+        Self: Validate,
+    {
+        self.validate()?;
+        self.save(db, filter).await
+    }
 
-        // Ensure we have a valid filter.
-        let filter = match filter {
-            Some(mut doc) => {
-                doc.insert("_id", id);
-                doc
-            }
-            None => doc! {"_id": id},
-        };
-
-        // Ensure that journaling is set to true for this call for full output document.
-        let options = match opts {
-            Some(mut options) => {
-                options.write_concern = match options.write_concern {
-                    Some(mut wc) => {
-                        wc.journal = Some(true);
-                        Some(wc)
-                    }
-                    None => {
-                        let mut wc = Self::write_concern().unwrap_or_default();
-                        wc.journal = Some(true);
-                        Some(wc)
-                    }
-                };
-                options
-            }
-            None => {
-                let mut options = options::FindOneAndUpdateOptions::default();
-                let mut wc = Self::write_concern().unwrap_or_default();
-                wc.journal = Some(true);
-                options.write_concern = Some(wc);
-                options
+    /// Save the current model instance, retrying on transient & duplicate key errors.
+    ///
+    /// This behaves exactly as `Model::save`, but attempts which fail with a transient error, EG
+    /// a write conflict, or with a duplicate key error, EG from a racing upsert, are retried up to
+    /// `max_retries` times, with an exponential backoff between attempts. Any other error is
+    /// returned immediately, as is the last error once all retries are exhausted.
+    async fn save_with_retry(&mut self, db: &Database, filter: Option<Document>, max_retries: u32) -> Result<()> {
+        let mut backoff = SAVE_RETRY_INITIAL_BACKOFF;
+        let mut attempt = 0;
+        loop {
+            match self.save(db, filter.clone()).await {
+                Err(err) if attempt < max_retries && (err.is_transient() || err.is_duplicate_key()) => {
+                    attempt += 1;
+                    debug!("Retrying save into '{}' after error: {}.", Self::resolve_collection_name(), err);
+                    sleep(backoff).await;
+                    backoff = std::cmp::min(backoff * 2, SAVE_RETRY_MAX_BACKOFF);
+                }
+                res => return res,
             }
-        };
+        }
+    }
+
+    /// Snapshot the serialized form of this instance, marking it as clean for `save_if_dirty`.
+    ///
+    /// This is typically called right after loading an instance. Returns
+    /// `WitherError::SnapshotFieldRequired` if the model declares no snapshot field.
+    fn mark_clean(&mut self) -> Result<()> {
+        let document = self.document_from_instance()?;
+        *self.snapshot_mut().ok_or(WitherError::SnapshotFieldRequired)? = Some(document);
+        Ok(())
+    }
+
+    /// Whether this instance has changed since it was last marked clean via `mark_clean`.
+    ///
+    /// An instance which was never marked clean is always dirty. Returns
+    /// `WitherError::SnapshotFieldRequired` if the model declares no snapshot field.
+    fn is_dirty(&self) -> Result<bool> {
+        Ok(match self.snapshot().ok_or(WitherError::SnapshotFieldRequired)? {
+            Some(snapshot) => *snapshot != self.document_from_instance()?,
+            None => true,
+        })
+    }
+
+    /// Save the current model instance via `save`, but only if it has changed since it was last
+    /// marked clean, returning whether it was saved.
+    ///
+    /// The serialized form of this instance is compared against its snapshot, and no request is
+    /// made to the database if the two are equal. After a save, this instance is marked clean.
+    /// Returns `WitherError::SnapshotFieldRequired` if the model declares no snapshot field.
+    async fn save_if_dirty(&mut self, db: &Database) -> Result<bool> {
+        if !self.is_dirty()? {
+            return Ok(false);
+        }
+        self.save(db, None).await?;
+        self.mark_clean()?;
+        Ok(true)
+    }
+
+    /// Save the current model instance, guarding against concurrent modification.
+    ///
+    /// The model's `version_field` is incremented as part of the write; a missing or null version
+    /// is treated as `0`. An instance without an ID is inserted as a new document. Otherwise, the
+    /// stored document is replaced only if its version still matches the version of this instance,
+    /// else a `WitherError::VersionConflict` is returned and this instance is left unchanged.
+    /// Fields listed in `Model::no_overwrite_fields` are preserved, exactly as with `save`.
+    async fn save_versioned(&mut self, db: &Database) -> Result<()> {
+        save_versioned_model(self, db, &Self::resolve_collection_name()).await
+    }
+
+    /// Save the current model instance as a new document, using a plain insert.
+    ///
+    /// This avoids the upsert round-trip performed by `save`, and is well suited for high insert
+    /// rate workloads. The ID of the inserted document will be set on this instance.
+    ///
+    /// If the instance already has an ID, this method will return an error. Use `save` or
+    /// `update` for instances which have already been written to the database.
+    async fn save_new(&mut self, db: &Database) -> Result<()> {
+        ensure_writable::<Self>()?;
+        if self.id().is_some() {
+            return Err(WitherError::ModelIdAlreadySet);
+        }
+        let coll = Self::collection(db);
+        let res = logging::instrument("insert_one", coll.namespace(), coll.insert_one(&(*self), None)).await?;
+        let id = res
+            .inserted_id
+            .as_object_id()
+            .ok_or(WitherError::ServerFailedToReturnObjectId)?;
+        self.set_id(id);
+        Ok(())
+    }
 
-        // Perform a FindOneAndUpdate operation on this model's document by ID.
-        Ok(Self::collection(db)
-            .find_one_and_update(filter, update, Some(options))
+    /// Insert the given instance as a new document, returning it with its ID set.
+    ///
+    /// This behaves exactly as `save_new`, but takes ownership of the instance, which is handy
+    /// for builder-style flows where a mutable binding is not wanted. If the instance already has
+    /// an ID, `WitherError::ModelIdAlreadySet` is returned.
+    async fn insert_one(instance: Self, db: &Database) -> Result<Self> {
+        let mut instance = instance;
+        instance.save_new(db).await?;
+        Ok(instance)
+    }
+
+    /// Replace the existing document of the current model instance entirely, by its ID.
+    ///
+    /// Unlike `save`, this never creates a new document: if no document has this instance's ID,
+    /// `WitherError::DocumentNotFound` is returned. If the instance has no ID, this method will
+    /// return an error. The model's write concern is used as is.
+    async fn replace(&self, db: &Database) -> Result<()> {
+        ensure_writable::<Self>()?;
+        let id = self.id().ok_or(WitherError::ModelIdRequiredForOperation)?;
+        let options = options::ReplaceOptions::builder().upsert(Some(false)).build();
+        let coll = Self::collection(db);
+        let res = logging::instrument("replace_one", coll.namespace(), coll.replace_one(doc! {"_id": id}, self, Some(options))).await?;
+        if res.matched_count == 0 {
+            return Err(WitherError::DocumentNotFound(Self::resolve_collection_name()));
+        }
+        Ok(())
+    }
+
+    /// Reload the current model instance from the database, replacing its contents in place.
+    ///
+    /// If this model instance has no ID, this method will return an error. If the model's
+    /// document no longer exists in the database, `WitherError::DocumentNotFound` is returned.
+    async fn reload(&mut self, db: &Database) -> Result<()> {
+        let id = self.id().ok_or(WitherError::ModelIdRequiredForOperation)?;
+        let current = Self::find_one(db, doc! {"_id": id}, None)
             .await?
-            .ok_or(WitherError::ServerFailedToReturnUpdatedDoc)?)
+            .ok_or_else(|| WitherError::DocumentNotFound(Self::resolve_collection_name()))?;
+        *self = current;
+        Ok(())
+    }
+
+    /// Find the document matching the given filter, or insert this instance if none matches,
+    /// returning the resulting model.
+    ///
+    /// This is performed as a single upsert using `$setOnInsert`, so it is safe under concurrency:
+    /// at most one document will be inserted for the filter. If this instance has no ID, one is
+    /// generated; the ID is set on this instance only if it was the one inserted.
+    async fn find_one_or_insert(&mut self, db: &Database, filter: Document) -> Result<Self> {
+        ensure_writable::<Self>()?;
+        // The default ObjectId is a newly generated one.
+        let id = self.id().unwrap_or_default();
+        let mut instance = Self::document_from_instance(self)?;
+        instance.insert("_id", id);
+        let options = options::FindOneAndUpdateOptions::builder()
+            .upsert(Some(true))
+            .return_document(Some(options::ReturnDocument::After))
+            .write_concern(Self::write_concern())
+            .build();
+        let coll = Self::collection(db);
+        let model = logging::instrument(
+            "find_one_and_update",
+            coll.namespace(),
+            coll.find_one_and_update(filter, doc! {"$setOnInsert": instance}, Some(options)),
+        )
+        .await?
+        .ok_or(WitherError::ServerFailedToReturnUpdatedDoc)?;
+        if model.id() == Some(id) {
+            self.set_id(id);
+        }
+        Ok(model)
+    }
+
+    /// Update the current model instance.
+    ///
+    /// This operation will always target the model instance by the instance's ID. If its ID is
+    /// `None`, this method will return an error. If a filter document is provided, this method
+    /// will ensure that the key `_id` is set to this model's ID.
+    ///
+    /// This method will consume `self`, and will return a new instance of `Self` based on the given
+    /// return options (`ReturnDocument::Before | ReturnDocument:: After`).
+    ///
+    /// In order to provide consistent behavior, this method will also ensure that the operation's
+    /// write concern `journaling` is set to `true`, so that we can receive a complete output
+    /// document, unless `Model::save_forces_journal` returns `false`.
+    ///
+    /// If this model instance was never written to the database, this operation will return an
+    /// error. If the filter matched no document, `WitherError::DocumentNotFound` is returned.
+    async fn update(self, db: &Database, filter: Option<Document>, update: Document, opts: Option<options::FindOneAndUpdateOptions>) -> Result<Self> {
+        update_model(self, Self::collection(db), filter, update, opts).await
+    }
+
+    /// Update the current model instance in the given collection.
+    ///
+    /// This behaves exactly as `Model::update`. See `Model::collection_named` for details.
+    async fn update_in(
+        self, db: &Database, collection_name: &str, filter: Option<Document>, update: Document, opts: Option<options::FindOneAndUpdateOptions>,
+    ) -> Result<Self> {
+        update_model(self, Self::collection_named(db, collection_name), filter, update, opts).await
+    }
+
+    /// Apply the given closure to the current model instance, then persist only what changed.
+    ///
+    /// The instance is serialized before & after the closure runs, and the two documents are
+    /// diffed into a minimal `$set` & `$unset` update, which is applied to this instance's
+    /// document by ID. Nested documents are diffed field by field, while arrays & other values
+    /// are replaced wholesale. Unlike `save`, this leaves fields which were concurrently updated by
+    /// others untouched, unless they were also changed by the closure.
+    ///
+    /// If the closure changes nothing, no request is made to the database. If this instance has
+    /// no ID, `WitherError::ModelIdRequiredForOperation` is returned, and if no document matches
+    /// its ID, `WitherError::DocumentNotFound` is returned.
+    async fn update_self<F>(&mut self, db: &Database, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut Self) + Send,
+    {
+        ensure_writable::<Self>()?;
+        let id = self.id().ok_or(WitherError::ModelIdRequiredForOperation)?;
+        let before = self.document_from_instance()?;
+        f(self);
+        let after = self.document_from_instance()?;
+        let update = diff_documents(&before, &after);
+        if update.is_empty() {
+            return Ok(());
+        }
+        let coll = Self::collection(db);
+        let res = logging::instrument("update_one", coll.namespace(), coll.update_one(doc! {"_id": id}, update, None)).await?;
+        if res.matched_count == 0 {
+            return Err(WitherError::DocumentNotFound(Self::resolve_collection_name()));
+        }
+        Ok(())
     }
 
     /// Delete this model instance by ID.
     ///
     /// Wraps the driver's `Collection.delete_one` method.
     async fn delete(&self, db: &Database) -> Result<DeleteResult> {
+        ensure_writable::<Self>()?;
+        // Return an error if the instance was never saved.
+        let id = self.id().ok_or(WitherError::ModelIdRequiredForOperation)?;
+        let coll = Self::collection(db);
+        Ok(logging::instrument("delete_one", coll.namespace(), coll.delete_one(doc! {"_id": id}, None)).await?)
+    }
+
+    /// Delete this model instance by ID, asserting that exactly its document was deleted.
+    ///
+    /// This behaves exactly as `Model::delete`, but returns `WitherError::DocumentNotFound` if no
+    /// document was deleted, EG as it was already deleted elsewhere, for call sites where this
+    /// instance's document must exist.
+    async fn delete_expecting_one(&self, db: &Database) -> Result<()> {
+        match self.delete(db).await?.deleted_count {
+            1 => Ok(()),
+            _ => Err(WitherError::DocumentNotFound(Self::resolve_collection_name())),
+        }
+    }
+
+    /// Delete this model instance by ID, from the given collection.
+    ///
+    /// This behaves exactly as `Model::delete`. See `Model::collection_named` for details.
+    async fn delete_in(&self, db: &Database, collection_name: &str) -> Result<DeleteResult> {
+        ensure_writable::<Self>()?;
         // Return an error if the instance was never saved.
         let id = self.id().ok_or(WitherError::ModelIdRequiredForOperation)?;
-        Ok(Self::collection(db).delete_one(doc! {"_id": id}, None).await?)
+        let coll = Self::collection_named(db, collection_name);
+        Ok(logging::instrument("delete_one", coll.namespace(), coll.delete_one(doc! {"_id": id}, None)).await?)
+    }
+
+    /// Deletes the first document stored in the collection matching filter.
+    ///
+    /// Wraps the driver's `Collection.delete_one` method.
+    async fn delete_one<O>(db: &Database, filter: Document, options: O) -> Result<DeleteResult>
+    where
+        O: Into<Option<options::DeleteOptions>> + Send,
+    {
+        ensure_writable::<Self>()?;
+        let coll = Self::collection(db);
+        Ok(logging::instrument("delete_one", coll.namespace(), coll.delete_one(filter, options)).await?)
+    }
+
+    /// Deletes all documents stored in the collection matching filter.
+    ///
+    /// Wraps the driver's `Collection.delete_many` method.
+    async fn delete_many<O>(db: &Database, filter: Document, options: O) -> Result<DeleteResult>
+    where
+        O: Into<Option<options::DeleteOptions>> + Send,
+    {
+        ensure_writable::<Self>()?;
+        let coll = Self::collection(db);
+        Ok(logging::instrument("delete_many", coll.namespace(), coll.delete_many(filter, options)).await?)
+    }
+
+    //////////////////////////////////////////////////////////////////////////////////////////////
+    // Convenience Methods ///////////////////////////////////////////////////////////////////////
+
+    /// Attempt to serialize the given bson document into an instance of this model.
+    fn instance_from_document(document: Document) -> Result<Self> {
+        Ok(from_bson::<Self>(Bson::Document(document))?)
+    }
+
+    /// Attempt to serialize the given bson document into an instance of this model, along with
+    /// whether any serde defaults were applied in doing so.
+    ///
+    /// Defaults are detected by comparing the top-level keys of the given document against those
+    /// of the re-serialized instance, so only defaulted top-level fields are detected, and fields
+    /// skipped during serialization are never reported.
+    fn instance_from_document_defaulted(document: Document) -> Result<(Self, bool)> {
+        let original = document.clone();
+        let model = Self::instance_from_document(document)?;
+        let defaulted = !missing_fields(&original, &model.document_from_instance()?).is_empty();
+        Ok((model, defaulted))
+    }
+
+    /// Attempt to serialize an instance of this model into a bson document.
+    fn document_from_instance(&self) -> Result<Document> {
+        match to_bson(&self)? {
+            Bson::Document(doc) => Ok(doc),
+            bsn => Err(WitherError::ModelSerToDocument(bsn.element_type())),
+        }
+    }
+
+    /// Build a write model which will insert this instance as part of a bulk write.
+    fn insert_one_model(&self) -> Result<WriteModel> {
+        Ok(WriteModel::InsertOne { document: self.document_from_instance()? })
+    }
+
+    /// Build a write model which will replace this instance by ID as part of a bulk write.
+    ///
+    /// If the instance has no ID, this method will return an error.
+    fn replace_one_model(&self, upsert: bool) -> Result<WriteModel> {
+        let id = self.id().ok_or(WitherError::ModelIdRequiredForOperation)?;
+        Ok(WriteModel::ReplaceOne {
+            filter: doc! {"_id": id},
+            replacement: self.document_from_instance()?,
+            upsert,
+        })
+    }
+
+    //////////////////////////////////////////////////////////////////////////////////////////////
+    // Maintenance Layer /////////////////////////////////////////////////////////////////////////
+
+    /// Get the vector of index models for this model.
+    fn indexes() -> Vec<IndexModel> {
+        vec![]
+    }
+
+    /// Synchronize this model with the backend.
+    ///
+    /// This routine should be called once per model, early on at boottime. It will synchronize
+    /// any indexes defined on this model with the backend.
+    ///
+    /// This routine will destroy any indexes found on this model's collection which are not
+    /// defined in this model's `indexes` method.
+    async fn sync(db: &Database) -> Result<()> {
+        Self::sync_with(db, None).await
+    }
+
+    /// Synchronize this model with the backend, using the given options.
+    ///
+    /// This behaves exactly as `Model::sync`, but allows for a `commitQuorum` to be given for
+    /// the index builds, and for progress to be logged. See `SyncOptions` for details.
+    async fn sync_with<O>(db: &Database, options: O) -> Result<()>
+    where
+        O: Into<Option<SyncOptions>> + Send,
+    {
+        let options = options.into().unwrap_or_default();
+        let coll = Self::collection(db);
+        logging::instrument("sync", coll.namespace(), async {
+            if let Some(capped) = Self::capped() {
+                sync_capped_collection(db, &coll, capped, &options).await?;
+            }
+            if let Some(validator) = Self::validator() {
+                sync_validator::<Self>(db, &coll, validator).await?;
+            }
+            let current_indexes = get_current_indexes(db, &coll).await?;
+            sync_model_indexes(db, &coll, Self::indexes(), current_indexes, &options).await
+        })
+        .await
+    }
+
+    /// Ensure that the given index exists on this model's collection, creating it if needed.
+    ///
+    /// This is the surgical counterpart to `Model::sync`: a single `createIndexes` is issued for
+    /// exactly the given index, and no other index is dropped or created, which makes it safe
+    /// for lazily creating incidental indexes, EG for reporting, in shared environments. The
+    /// index need not be declared on the model. If the index is unnamed, its name is generated
+    /// from its keys, as done by `sync`. Creating an index which already exists with the same
+    /// options is a no-op on the server, while differing options produce an error.
+    async fn ensure_index(db: &Database, index: IndexModel) -> Result<()> {
+        ensure_writable::<Self>()?;
+        let coll = Self::collection(db);
+        let index = build_aspired_indexes_map(&[index])?.into_values();
+        create_indexes(db, &coll, index, None).await
+    }
+
+    /// Check whether this model's declared indexes exactly match the indexes on its collection.
+    ///
+    /// This is a read-only dry-run of `Model::sync`: the same diff is performed, but instead of
+    /// dropping & creating indexes, `false` is returned if `sync` would need to do either. Useful
+    /// for detecting index drift, EG in CI. Only indexes are checked, not capped parameters.
+    async fn indexes_in_sync(db: &Database) -> Result<bool> {
+        let coll = Self::collection(db);
+        let aspired_indexes = build_aspired_indexes_map(&Self::indexes())?;
+        let current_indexes = get_current_indexes(db, &coll).await?;
+        let (indexes_to_drop, indexes_to_create) = diff_indexes(&aspired_indexes, &current_indexes);
+        Ok(indexes_to_drop.is_empty() && indexes_to_create.is_empty())
+    }
+
+    /// Check whether this model's collection currently exists in the given database.
+    ///
+    /// A collection is typically created implicitly by its first write, or by its first index.
+    async fn collection_exists(db: &Database) -> Result<bool> {
+        let names = db
+            .list_collection_names(doc! {"name": Self::resolve_collection_name()})
+            .await?;
+        Ok(!names.is_empty())
+    }
+
+    /// Get current collection indexes, if any.
+    async fn get_current_indexes(db: &Database) -> Result<HashMap<String, IndexModel>> {
+        let coll = Self::collection(db);
+        get_current_indexes(db, &coll).await
+    }
+
+    /// Get a typed summary of the current collection indexes, if any, ordered by index name.
+    ///
+    /// The default index on `_id` is not included, exactly as with `get_current_indexes`.
+    async fn index_info(db: &Database) -> Result<Vec<IndexInfo>> {
+        let coll = Self::collection(db);
+        let current_indexes: BTreeMap<_, _> = get_current_indexes(db, &coll).await?.into_iter().collect();
+        Ok(current_indexes
+            .into_iter()
+            .map(|(name, model)| index_info_from_model(name, &model))
+            .collect())
+    }
+
+    /// Drop this model's collection, along with all of its documents & indexes.
+    ///
+    /// Wraps the driver's `Collection.drop` method. This is a no-op if the collection does not
+    /// exist.
+    async fn drop_collection(db: &Database) -> Result<()> {
+        ensure_writable::<Self>()?;
+        let coll = Self::collection(db);
+        Ok(logging::instrument("drop", coll.namespace(), coll.drop(None)).await?)
+    }
+
+    /// Drop all indexes on this model's collection, except for the default index on `_id`.
+    ///
+    /// Wraps the driver's `Collection.drop_indexes` method. This is a no-op if the collection does
+    /// not exist.
+    async fn drop_indexes(db: &Database) -> Result<()> {
+        ensure_writable::<Self>()?;
+        let coll = Self::collection(db);
+        match logging::instrument("drop_indexes", coll.namespace(), coll.drop_indexes(None)).await {
+            Ok(_) => Ok(()),
+            Err(err) => match err.kind.as_ref() {
+                // The DB & or collection does not yet exist. Move on.
+                mongodb::error::ErrorKind::Command(err) if err.code == 26 => Ok(()),
+                _ => Err(err.into()),
+            },
+        }
+    }
+}
+
+/// Ergonomic helpers available on all `Model` types.
+///
+/// This trait is blanket-implemented for every `Model`, so no per-model boilerplate is required.
+pub trait ModelExt: Model {
+    /// Create a new instance of this model which has not yet been saved, and so has no ID.
+    fn new_unsaved() -> Self
+    where
+        Self: Default,
+    {
+        Self::default()
+    }
+
+    /// Set the given ID on this model instance, returning the updated instance.
+    fn with_id(mut self, id: ObjectId) -> Self {
+        self.set_id(id);
+        self
+    }
+}
+
+impl<T: Model> ModelExt for T {}
+
+/// Get a handle to the named collection for the given model type, using the given collection
+/// options. Any options which are not set will fall back to the model's configured values.
+fn collection_with_name<T: Model>(db: &Database, name: &str, opts: options::CollectionOptions) -> Collection<T> {
+    db.collection_with_options(
+        name,
+        options::CollectionOptions::builder()
+            .selection_criteria(opts.selection_criteria.or_else(T::selection_criteria))
+            .read_concern(opts.read_concern.or_else(T::read_concern))
+            .write_concern(opts.write_concern.or_else(T::write_concern))
+            .build(),
+    )
+}
+
+/// Apply the model's default hint, collation & max time to the given find options, where unset.
+fn find_options_with_defaults<T: Model>(options: Option<options::FindOptions>) -> Option<options::FindOptions> {
+    let (hint, collation, max_time) = (T::default_hint(), T::default_collation(), T::default_max_time());
+    if hint.is_none() && collation.is_none() && max_time.is_none() {
+        return options;
+    }
+    let mut options = options.unwrap_or_default();
+    options.hint = options.hint.or(hint);
+    options.collation = options.collation.or(collation);
+    options.max_time = options.max_time.or(max_time);
+    Some(options)
+}
+
+/// Apply the model's default hint, collation & max time to the given find one options, where
+/// unset.
+fn find_one_options_with_defaults<T: Model>(options: Option<options::FindOneOptions>) -> Option<options::FindOneOptions> {
+    let (hint, collation, max_time) = (T::default_hint(), T::default_collation(), T::default_max_time());
+    if hint.is_none() && collation.is_none() && max_time.is_none() {
+        return options;
+    }
+    let mut options = options.unwrap_or_default();
+    options.hint = options.hint.or(hint);
+    options.collation = options.collation.or(collation);
+    options.max_time = options.max_time.or(max_time);
+    Some(options)
+}
+
+/// Apply the model's default max time to the given count options, where unset.
+fn count_options_with_defaults<T: Model>(options: Option<options::CountOptions>) -> Option<options::CountOptions> {
+    let max_time = match T::default_max_time() {
+        Some(max_time) => max_time,
+        None => return options,
+    };
+    let mut options = options.unwrap_or_default();
+    options.max_time = options.max_time.or(Some(max_time));
+    Some(options)
+}
+
+/// Update the given model instance in the given collection.
+///
+/// See `Model::update` for details on this behavior.
+async fn update_model<T: Model>(
+    model: T, coll: Collection<T>, filter: Option<Document>, update: Document, opts: Option<options::FindOneAndUpdateOptions>,
+) -> Result<T> {
+    ensure_writable::<T>()?;
+    // Extract model's ID & use as filter for this operation.
+    let id = model.id().ok_or(WitherError::ModelIdRequiredForOperation)?;
+
+    // Ensure we have a valid filter.
+    let filter = match filter {
+        Some(mut doc) => {
+            doc.insert("_id", id);
+            doc
+        }
+        None => doc! {"_id": id},
+    };
+
+    // Ensure that journaling is set to true for this call for full output document.
+    let force_journal = T::save_forces_journal();
+    let options = match opts {
+        Some(mut options) => {
+            options.write_concern = match options.write_concern {
+                Some(mut wc) => {
+                    if force_journal {
+                        wc.journal = Some(true);
+                    }
+                    Some(wc)
+                }
+                None => {
+                    let mut wc = T::write_concern().unwrap_or_default();
+                    if force_journal {
+                        wc.journal = Some(true);
+                    }
+                    Some(wc)
+                }
+            };
+            options
+        }
+        None => {
+            let mut options = options::FindOneAndUpdateOptions::default();
+            let mut wc = T::write_concern().unwrap_or_default();
+            if force_journal {
+                wc.journal = Some(true);
+            }
+            options.write_concern = Some(wc);
+            options
+        }
+    };
+
+    // Perform a FindOneAndUpdate operation on this model's document by ID. Without an upsert,
+    // an empty response means the filter matched nothing; with one, the server misbehaved.
+    let upsert = options.upsert.unwrap_or(false);
+    logging::instrument("update", coll.namespace(), coll.find_one_and_update(filter, update, Some(options)))
+        .await?
+        .ok_or_else(|| match upsert {
+            true => WitherError::ServerFailedToReturnUpdatedDoc,
+            false => WitherError::DocumentNotFound(coll.name().to_string()),
+        })
+}
+
+/// Save the given model instance, optionally as part of the given session.
+///
+/// See `Model::save` for details on this behavior.
+async fn save_model<T: Model>(
+    model: &mut T, db: &Database, collection_name: &str, session: Option<&mut ClientSession>, filter: Option<Document>,
+) -> Result<()> {
+    ensure_writable::<T>()?;
+    // Ensure that journaling is set to true for this call, as we need to be able to get an ID back.
+    // This is applied to the collection, so that the transaction's write concern takes precedence
+    // when within a transaction.
+    let mut write_concern = T::write_concern().unwrap_or_default();
+    if T::save_forces_journal() {
+        write_concern.journal = Some(true);
+    }
+    let coll = collection_with_name::<T>(
+        db,
+        collection_name,
+        options::CollectionOptions::builder()
+            .write_concern(Some(write_concern))
+            .build(),
+    );
+
+    // Handle case where instance already has an ID.
+    let mut id_needs_update = false;
+    let filter = match (model.id(), filter) {
+        (Some(id), _) => doc! {"_id": id},
+        (None, None) => {
+            let new_id = ObjectId::new();
+            model.set_id(new_id);
+            doc! {"_id": new_id}
+        }
+        (None, Some(filter)) => {
+            id_needs_update = true;
+            filter
+        }
+    };
+
+    // Save the record by replacing it entirely, or by updating all fields which may be
+    // overwritten, upserting if it doesn't already exist. Only the `_id` of the saved document is
+    // needed back, so the rest of it is projected away.
+    let coll = coll.clone_with_type::<Document>();
+    let replacement = model.document_from_instance()?;
+    let updated_doc = match save_update_document::<T>(&replacement) {
+        Some(update) => {
+            let opts = options::FindOneAndUpdateOptions::builder()
+                .upsert(Some(true))
+                .return_document(Some(options::ReturnDocument::After))
+                .projection(Some(doc! {"_id": 1}))
+                .build();
+            match session {
+                Some(session) => {
+                    let update = coll.find_one_and_update_with_session(filter, update, Some(opts), session);
+                    logging::instrument("save", coll.namespace(), update).await?
+                }
+                None => logging::instrument("save", coll.namespace(), coll.find_one_and_update(filter, update, Some(opts))).await?,
+            }
+        }
+        None => {
+            let opts = options::FindOneAndReplaceOptions::builder()
+                .upsert(Some(true))
+                .return_document(Some(options::ReturnDocument::After))
+                .projection(Some(doc! {"_id": 1}))
+                .build();
+            match session {
+                Some(session) => {
+                    let replace = coll.find_one_and_replace_with_session(filter, replacement, Some(opts), session);
+                    logging::instrument("save", coll.namespace(), replace).await?
+                }
+                None => logging::instrument("save", coll.namespace(), coll.find_one_and_replace(filter, replacement, Some(opts))).await?,
+            }
+        }
+    };
+    let updated_doc = updated_doc.ok_or(WitherError::ServerFailedToReturnUpdatedDoc)?;
+
+    // Update instance ID if needed.
+    if id_needs_update {
+        let response_id = updated_doc
+            .get_object_id("_id")
+            .map_err(|_| WitherError::ServerFailedToReturnObjectId)?;
+        model.set_id(response_id);
+    };
+    Ok(())
+}
+
+/// Build the update document to save the given serialized instance with, when the model has
+/// fields which must not be overwritten, per `Model::no_overwrite_fields`.
+///
+/// Returns `None` when the instance is to be saved by replacing its document entirely.
+fn save_update_document<T: Model>(replacement: &Document) -> Option<Document> {
+    let no_overwrite = T::no_overwrite_fields();
+    if no_overwrite.is_empty() {
+        return None;
+    }
+    let (mut set, mut set_on_insert) = (Document::new(), Document::new());
+    for (key, val) in replacement {
+        if key == "_id" {
+            continue;
+        }
+        match no_overwrite.contains(&key.as_str()) {
+            true => set_on_insert.insert(key.clone(), val.clone()),
+            false => set.insert(key.clone(), val.clone()),
+        };
+    }
+    // An empty update is rejected by the server, so an instance serialized as only its `_id`
+    // sets just that on insert, leaving an existing document untouched. Without an `_id`, one is
+    // generated for the upserted document, as the server would.
+    if set_on_insert.is_empty() && set.is_empty() {
+        let id = replacement.get("_id").cloned().unwrap_or_else(|| ObjectId::new().into());
+        set_on_insert.insert("_id", id);
+    }
+    let mut update = Document::new();
+    if !set.is_empty() {
+        update.insert("$set", set);
+    }
+    if !set_on_insert.is_empty() {
+        update.insert("$setOnInsert", set_on_insert);
+    }
+    Some(update)
+}
+
+/// Ensure the given model may be written to, per `Model::read_only`.
+fn ensure_writable<T: Model>() -> Result<()> {
+    if T::read_only() {
+        return Err(WitherError::ReadOnlyModel(T::resolve_collection_name()));
+    }
+    Ok(())
+}
+
+/// Sleep for the given duration on the async runtime selected via crate features.
+#[cfg(feature = "tokio-runtime")]
+async fn sleep(duration: std::time::Duration) {
+    tokio::time::sleep(duration).await
+}
+
+/// Sleep for the given duration on the async runtime selected via crate features.
+#[cfg(all(feature = "async-std-runtime", not(feature = "tokio-runtime")))]
+async fn sleep(duration: std::time::Duration) {
+    async_std::task::sleep(duration).await
+}
+
+/// Save the given model instance, replacing the stored document only if its version matches.
+///
+/// See `Model::save_versioned` for details on this behavior.
+async fn save_versioned_model<T: Model>(model: &mut T, db: &Database, collection_name: &str) -> Result<()> {
+    ensure_writable::<T>()?;
+    let field = T::version_field().ok_or(WitherError::VersionFieldRequired)?;
+    let mut document = model.document_from_instance()?;
+    let current = document.get(field).cloned().unwrap_or(Bson::Null);
+    let next = match &current {
+        Bson::Null => Some(Bson::Int32(1)),
+        Bson::Int32(version) => version.checked_add(1).map(Bson::Int32),
+        Bson::Int64(version) => version.checked_add(1).map(Bson::Int64),
+        _ => None,
+    }
+    .ok_or_else(|| WitherError::InvalidVersionField(field.to_string()))?;
+    document.insert(field, next);
+
+    // Journaling is applied as in `save_model`.
+    let mut write_concern = T::write_concern().unwrap_or_default();
+    if T::save_forces_journal() {
+        write_concern.journal = Some(true);
+    }
+    let coll = collection_with_name::<T>(
+        db,
+        collection_name,
+        options::CollectionOptions::builder()
+            .write_concern(Some(write_concern))
+            .build(),
+    );
+    *model = match model.id() {
+        Some(id) => {
+            let filter = doc! {"_id": id, field: current};
+            let update = save_update_document::<T>(&document);
+            let updated = T::instance_from_document(document)?;
+            let result = match update {
+                Some(update) => {
+                    let update = coll.update_one(filter, update, None);
+                    logging::instrument("save_versioned", coll.namespace(), update).await?
+                }
+                None => {
+                    let replace = coll.replace_one(filter, &updated, None);
+                    logging::instrument("save_versioned", coll.namespace(), replace).await?
+                }
+            };
+            if result.matched_count == 0 {
+                return Err(WitherError::VersionConflict(collection_name.to_string()));
+            }
+            updated
+        }
+        None => {
+            let mut updated = T::instance_from_document(document)?;
+            updated.set_id(ObjectId::new());
+            logging::instrument("save_versioned", coll.namespace(), coll.insert_one(&updated, None)).await?;
+            updated
+        }
+    };
+    Ok(())
+}
+
+/// Save the given model instance, reporting whether the document was inserted or replaced.
+///
+/// See `Model::save_outcome` for details on this behavior.
+async fn save_model_outcome<T: Model>(model: &mut T, db: &Database, collection_name: &str, filter: Option<Document>) -> Result<SaveOutcome> {
+    ensure_writable::<T>()?;
+    // Same ID handling as `save_model`.
+    let filter = match (model.id(), filter) {
+        (Some(id), _) => doc! {"_id": id},
+        (None, None) => {
+            let new_id = ObjectId::new();
+            model.set_id(new_id);
+            doc! {"_id": new_id}
+        }
+        (None, Some(filter)) => filter,
+    };
+    let mut write_concern = T::write_concern().unwrap_or_default();
+    if T::save_forces_journal() {
+        write_concern.journal = Some(true);
+    }
+
+    // The driver's `find_one_and_replace` does not expose `lastErrorObject`, so the command is
+    // issued directly in order to learn whether an upsert took place.
+    let replacement = model.document_from_instance()?;
+    let mut command = doc! {
+        "findAndModify": collection_name,
+        "query": filter,
+        "update": save_update_document::<T>(&replacement).unwrap_or(replacement),
+        "upsert": true,
+        "new": true,
+        "fields": {"_id": 1},
+    };
+    if write_concern != options::WriteConcern::default() {
+        command.insert("writeConcern", to_document(&write_concern)?);
+    }
+    let namespace = Namespace {
+        db: db.name().to_string(),
+        coll: collection_name.to_string(),
+    };
+    let response = logging::instrument("save", namespace, db.run_command(command, None)).await?;
+
+    let id = response
+        .get_document("value")
+        .ok()
+        .and_then(|value| value.get_object_id("_id").ok())
+        .ok_or(WitherError::ServerFailedToReturnObjectId)?;
+    if model.id().is_none() {
+        model.set_id(id);
+    }
+    let updated_existing = response
+        .get_document("lastErrorObject")
+        .ok()
+        .and_then(|last_error| last_error.get_bool("updatedExisting").ok())
+        .unwrap_or(false);
+    Ok(match updated_existing {
+        true => SaveOutcome::Replaced { id },
+        false => SaveOutcome::Inserted { id },
+    })
+}
+
+/// Get the top-level fields of `upgraded` whose keys are missing from `original`.
+fn missing_fields(original: &Document, upgraded: &Document) -> Document {
+    upgraded
+        .iter()
+        .filter(|(key, _)| !original.contains_key(key))
+        .map(|(key, val)| (key.clone(), val.clone()))
+        .collect()
+}
+
+/// Compute a minimal `$set` & `$unset` update document which transforms `before` into `after`.
+///
+/// Returns an empty document when the two are equal.
+fn diff_documents(before: &Document, after: &Document) -> Document {
+    let (mut set, mut unset) = (Document::new(), Document::new());
+    diff_documents_into("", before, after, &mut set, &mut unset);
+    let mut update = Document::new();
+    if !set.is_empty() {
+        update.insert("$set", set);
     }
+    if !unset.is_empty() {
+        update.insert("$unset", unset);
+    }
+    update
+}
 
-    /// Deletes all documents stored in the collection matching filter.
-    ///
-    /// Wraps the driver's `Collection.delete_many` method.
-    async fn delete_many<O>(db: &Database, filter: Document, options: O) -> Result<DeleteResult>
-    where
-        O: Into<Option<options::DeleteOptions>> + Send,
-    {
-        Ok(Self::collection(db).delete_many(filter, options).await?)
+/// Accumulate the changes between the given documents, with keys prefixed by the given path.
+fn diff_documents_into(prefix: &str, before: &Document, after: &Document, set: &mut Document, unset: &mut Document) {
+    for (key, after_val) in after {
+        let path = format!("{}{}", prefix, key);
+        match (before.get(key), after_val) {
+            (Some(before_val), _) if before_val == after_val => (),
+            // Diff non-empty subdocuments field by field. An empty subdocument must be set
+            // explicitly, as unsetting each of its fields would not produce it.
+            (Some(Bson::Document(before_doc)), Bson::Document(after_doc)) if !after_doc.is_empty() => {
+                diff_documents_into(&format!("{}.", path), before_doc, after_doc, set, unset);
+            }
+            _ => {
+                set.insert(path, after_val.clone());
+            }
+        }
     }
+    for key in before.keys() {
+        if !after.contains_key(key) {
+            unset.insert(format!("{}{}", prefix, key), "");
+        }
+    }
+}
 
-    //////////////////////////////////////////////////////////////////////////////////////////////
-    // Convenience Methods ///////////////////////////////////////////////////////////////////////
+/// The kinds of write commands which write models are batched into.
+#[derive(Clone, Copy, PartialEq)]
+enum WriteCommand {
+    Insert,
+    Update,
+    Delete,
+}
 
-    /// Attempt to serialize the given bson document into an instance of this model.
-    fn instance_from_document(document: Document) -> Result<Self> {
-        Ok(from_bson::<Self>(Bson::Document(document))?)
+/// Build the statement for the given write model, along with the kind of command it belongs to.
+fn write_model_statement(op: WriteModel) -> Result<(WriteCommand, Document)> {
+    Ok(match op {
+        WriteModel::InsertOne { document } => (WriteCommand::Insert, document),
+        WriteModel::ReplaceOne { filter, replacement, upsert } => (
+            WriteCommand::Update,
+            doc! {"q": filter, "u": replacement, "upsert": upsert, "multi": false},
+        ),
+        WriteModel::UpdateOne { filter, update, upsert } => (
+            WriteCommand::Update,
+            doc! {"q": filter, "u": to_bson(&update)?, "upsert": upsert, "multi": false},
+        ),
+        WriteModel::UpdateMany { filter, update, upsert } => (
+            WriteCommand::Update,
+            doc! {"q": filter, "u": to_bson(&update)?, "upsert": upsert, "multi": true},
+        ),
+        WriteModel::DeleteOne { filter } => (WriteCommand::Delete, doc! {"q": filter, "limit": 1}),
+        WriteModel::DeleteMany { filter } => (WriteCommand::Delete, doc! {"q": filter, "limit": 0}),
+    })
+}
+
+/// Extract a count from a write command response, which may be encoded as any numeric type.
+fn response_count(response: &Document, key: &str) -> u64 {
+    match response.get(key) {
+        Some(Bson::Int32(val)) => *val as u64,
+        Some(Bson::Int64(val)) => *val as u64,
+        Some(Bson::Double(val)) => *val as u64,
+        _ => 0,
     }
+}
 
-    /// Attempt to serialize an instance of this model into a bson document.
-    fn document_from_instance(&self) -> Result<Document> {
-        match to_bson(&self)? {
-            Bson::Document(doc) => Ok(doc),
-            bsn => Err(WitherError::ModelSerToDocument(bsn.element_type())),
+/// Execute the given write models as a series of batched write commands.
+async fn bulk_write<T>(db: &Database, coll: &Collection<T>, ops: Vec<WriteModel>, options: BulkWriteOptions) -> Result<BulkWriteResult> {
+    let ordered = options.ordered.unwrap_or(true);
+
+    // Group consecutive write models of the same kind into batches, preserving order.
+    let mut batches: Vec<(WriteCommand, Vec<Document>)> = vec![];
+    for op in ops {
+        let (kind, statement) = write_model_statement(op)?;
+        match batches.last_mut() {
+            Some((last_kind, statements)) if *last_kind == kind => statements.push(statement),
+            _ => batches.push((kind, vec![statement])),
         }
     }
 
-    //////////////////////////////////////////////////////////////////////////////////////////////
-    // Maintenance Layer /////////////////////////////////////////////////////////////////////////
+    let mut result = BulkWriteResult::default();
+    let mut failure: Option<mongodb::error::BulkWriteFailure> = None;
+    let mut offset = 0;
+    for (kind, statements) in batches {
+        let batch_len = statements.len();
+        let (command_name, statements_key) = match kind {
+            WriteCommand::Insert => ("insert", "documents"),
+            WriteCommand::Update => ("update", "updates"),
+            WriteCommand::Delete => ("delete", "deletes"),
+        };
+        let mut command = doc! {
+            command_name: coll.name(),
+            statements_key: statements,
+            "ordered": ordered,
+        };
+        if let (Some(bypass), false) = (options.bypass_document_validation, kind == WriteCommand::Delete) {
+            command.insert("bypassDocumentValidation", bypass);
+        }
+        if let Some(write_concern) = &options.write_concern {
+            command.insert("writeConcern", to_document(write_concern)?);
+        }
+        let response = db.run_command(command, None).await?;
 
-    /// Get the vector of index models for this model.
-    fn indexes() -> Vec<IndexModel> {
-        vec![]
-    }
+        // Accumulate the counts reported for this batch.
+        let n = response_count(&response, "n");
+        match kind {
+            WriteCommand::Insert => result.inserted_count += n,
+            WriteCommand::Delete => result.deleted_count += n,
+            WriteCommand::Update => {
+                let upserted = response.get_array("upserted").map(|arr| arr.as_slice()).unwrap_or(&[]);
+                for upsert in upserted.iter().filter_map(|bsn| bsn.as_document()) {
+                    if let Some(id) = upsert.get("_id") {
+                        let index = response_count(upsert, "index") as usize + offset;
+                        result.upserted_ids.insert(index, id.clone());
+                    }
+                }
+                result.matched_count += n.saturating_sub(upserted.len() as u64);
+                result.modified_count += response_count(&response, "nModified");
+            }
+        }
 
-    /// Synchronize this model with the backend.
-    ///
-    /// This routine should be called once per model, early on at boottime. It will synchronize
-    /// any indexes defined on this model with the backend.
-    ///
-    /// This routine will destroy any indexes found on this model's collection which are not
-    /// defined in this model's `indexes` method.
-    async fn sync(db: &Database) -> Result<()> {
-        let coll = Self::collection(db);
-        let current_indexes = get_current_indexes(db, &coll).await?;
-        sync_model_indexes(db, &coll, Self::indexes(), current_indexes).await?;
-        Ok(())
+        // Accumulate any write errors reported for this batch.
+        let mut batch_failure: mongodb::error::BulkWriteFailure = from_document(response)?;
+        let has_write_errors = batch_failure
+            .write_errors
+            .as_ref()
+            .map(|errs| !errs.is_empty())
+            .unwrap_or(false);
+        if let Some(errs) = batch_failure.write_errors.as_mut() {
+            errs.iter_mut().for_each(|err| err.index += offset);
+        }
+        if has_write_errors || batch_failure.write_concern_error.is_some() {
+            failure = Some(match failure {
+                None => batch_failure,
+                Some(mut acc) => {
+                    if let Some(errs) = batch_failure.write_errors {
+                        acc.write_errors.get_or_insert_with(Vec::new).extend(errs);
+                    }
+                    if acc.write_concern_error.is_none() {
+                        acc.write_concern_error = batch_failure.write_concern_error;
+                    }
+                    acc
+                }
+            });
+        }
+        if has_write_errors && ordered {
+            break;
+        }
+        offset += batch_len;
     }
 
-    /// Get current collection indexes, if any.
-    async fn get_current_indexes(db: &Database) -> Result<HashMap<String, IndexModel>> {
-        let coll = Self::collection(db);
-        get_current_indexes(db, &coll).await
+    match failure {
+        Some(failure) => Err(mongodb::error::Error::from(mongodb::error::ErrorKind::BulkWrite(failure)).into()),
+        None => Ok(result),
     }
 }
 
@@ -333,18 +1869,205 @@ async fn get_current_indexes<T>(db: &Database, coll: &Collection<T>) -> Result<H
     Ok(build_index_map(list_indexes))
 }
 
+/// Get the capped parameters of the given collection, or `None` if it does not exist.
+///
+/// A collection which exists but is not capped is reported as `Some(None)`.
+async fn get_current_capped(db: &Database, name: &str) -> Result<Option<Option<CappedOptions>>> {
+    let opts = match get_collection_options(db, name).await? {
+        Some(opts) => opts,
+        None => return Ok(None),
+    };
+    Ok(Some(match (opts.capped, opts.size) {
+        (Some(true), Some(size)) => Some(CappedOptions::new(size, opts.max.filter(|max| *max > 0))),
+        _ => None,
+    }))
+}
+
+/// Get the options of the collection of the given name, or `None` if it does not exist.
+async fn get_collection_options(db: &Database, name: &str) -> Result<Option<options::CreateCollectionOptions>> {
+    use futures::stream::TryStreamExt;
+
+    let mut specs = db.list_collections(doc! {"name": name}, None).await?;
+    Ok(specs.try_next().await?.map(|spec| spec.options))
+}
+
+/// Check whether the current capped parameters of a collection satisfy the declared ones.
+///
+/// MongoDB may round the size of a capped collection up to a multiple of 256 bytes, so either
+/// the declared size or its rounded value is accepted.
+fn capped_matches(declared: CappedOptions, current: Option<CappedOptions>) -> bool {
+    let current = match current {
+        Some(current) => current,
+        None => return false,
+    };
+    let rounded_size = declared.size.saturating_add(255) / 256 * 256;
+    (current.size == declared.size || current.size == rounded_size) && current.max == declared.max
+}
+
+/// Create the capped collection of the given name.
+async fn create_capped_collection(db: &Database, name: &str, capped: CappedOptions) -> Result<()> {
+    let options = options::CreateCollectionOptions::builder()
+        .capped(true)
+        .size(capped.size)
+        .max(capped.max)
+        .build();
+    db.create_collection(name, options).await?;
+    Ok(())
+}
+
+/// Ensure the model's collection is a capped collection with the declared parameters.
+///
+/// A missing collection is created. On drift, a warning is logged, unless the collection is empty
+/// & `recreate_empty_capped` is set, in which case the collection is dropped & recreated.
+async fn sync_capped_collection<T>(db: &Database, coll: &Collection<T>, capped: CappedOptions, options: &SyncOptions) -> Result<()> {
+    let current = match get_current_capped(db, coll.name()).await? {
+        Some(current) => current,
+        None => {
+            info!("Creating capped collection '{}'.", coll.namespace());
+            return create_capped_collection(db, coll.name(), capped).await;
+        }
+    };
+    if capped_matches(capped, current) {
+        return Ok(());
+    }
+    if options.recreate_empty_capped && coll.estimated_document_count(None).await? == 0 {
+        warn!(
+            "Recreating empty collection '{}' as capped with size {} & max {:?}; it currently has {:?}.",
+            coll.namespace(),
+            capped.size,
+            capped.max,
+            current
+        );
+        coll.drop(None).await?;
+        return create_capped_collection(db, coll.name(), capped).await;
+    }
+    warn!(
+        "Collection '{}' is declared as capped with size {} & max {:?}, but currently has {:?}. \
+         MongoDB can not resize a capped collection in place, so the declared parameters are NOT applied. \
+         To apply them, create a new capped collection with the declared parameters, copy the documents \
+         over, then rename it over the old collection; or, if the collection is empty, sync with \
+         `SyncOptions::recreate_empty_capped` enabled.",
+        coll.namespace(),
+        capped.size,
+        capped.max,
+        current
+    );
+    Ok(())
+}
+
+/// Ensure the model's collection has the declared validator, validation level & action.
+///
+/// A missing collection is created with the validator. Otherwise, `collMod` is issued only if
+/// any of these differ from the collection's current options, where an unset level or action is
+/// taken to be the server's default.
+async fn sync_validator<T: Model>(db: &Database, coll: &Collection<T>, validator: Document) -> Result<()> {
+    let level = T::validation_level().unwrap_or(options::ValidationLevel::Strict);
+    let action = T::validation_action().unwrap_or(options::ValidationAction::Error);
+    let current = match get_collection_options(db, coll.name()).await? {
+        Some(current) => current,
+        None => {
+            info!("Creating collection '{}' with its validator.", coll.namespace());
+            let options = options::CreateCollectionOptions::builder()
+                .validator(validator)
+                .validation_level(level)
+                .validation_action(action)
+                .build();
+            db.create_collection(coll.name(), options).await?;
+            return Ok(());
+        }
+    };
+    if current.validator.as_ref() == Some(&validator)
+        && current.validation_level.unwrap_or(options::ValidationLevel::Strict) == level
+        && current.validation_action.unwrap_or(options::ValidationAction::Error) == action
+    {
+        return Ok(());
+    }
+    info!("Applying validator to collection '{}'.", coll.namespace());
+    let command = doc! {
+        "collMod": coll.name(),
+        "validator": validator,
+        "validationLevel": to_bson(&level)?,
+        "validationAction": to_bson(&action)?,
+    };
+    db.run_command(command, None).await?;
+    Ok(())
+}
+
 /// Generate an index name from the keys of the given document, matching the behavior of the
 /// index management spec.
 ///
 /// https://github.com/mongodb/specifications/blob/master/source/index-management.rst#index-name-generation
+///
+/// Wildcard keys, such as `$**` or `field.$**`, are used verbatim as the key portion of the name.
 fn generate_index_name_from_keys(keys: &Document) -> String {
-    let mut key = keys.iter().fold(String::from(""), |mut acc, (key, value)| {
-        acc.push_str(&format!("{}_{}_", key, value.as_i32().unwrap_or(0)));
-        acc
-    });
-    // Remove last underscore
-    key.pop();
-    key
+    keys.iter()
+        .map(|(key, value)| format!("{}_{}", key, index_key_name_component(value)))
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Get the value component of an index name for the given index key value.
+///
+/// This follows the server's naming convention: numeric directions are rendered as integers
+/// where they have no fractional part, and string values such as `"text"` or `"2dsphere"` are
+/// rendered verbatim.
+fn index_key_name_component(value: &Bson) -> String {
+    match value {
+        Bson::Int32(val) => val.to_string(),
+        Bson::Int64(val) => val.to_string(),
+        Bson::Double(val) if val.fract() == 0.0 => (*val as i64).to_string(),
+        Bson::Double(val) => val.to_string(),
+        Bson::String(val) => val.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Normalize the given index options for diffing purposes.
+///
+/// The server does not echo back `hidden: false`, so it is treated as equivalent to the option
+/// being absent. Otherwise, toggling an index's `hidden` flag should trigger a recreate.
+///
+/// The `wildcardProjection` of a wildcard index may be echoed back with booleans in place of
+/// numbers, and with an explicit `_id` exclusion, so it is normalized as well. The
+/// `textIndexVersion` of a text index is chosen by the server, so it is ignored.
+///
+/// The `background` option only affects how an index is built, never the resulting index, and
+/// servers since 4.2 ignore it & omit it from `listIndexes`, so it is ignored as well.
+fn normalize_index_options(options: &Option<Document>) -> Option<Document> {
+    options.as_ref().map(|options| {
+        let mut options = options.clone();
+        if let Ok(false) = options.get_bool("hidden") {
+            options.remove("hidden");
+        }
+        options.remove("textIndexVersion");
+        options.remove("background");
+        if let Ok(projection) = options.get_document("wildcardProjection") {
+            let mut projection = normalize_projection(projection);
+            if let Some(Bson::Int32(0)) = projection.get("_id") {
+                projection.remove("_id");
+            }
+            options.insert("wildcardProjection", projection);
+        }
+        options
+    })
+}
+
+/// Normalize the inclusion & exclusion values of the given projection to `1` & `0`.
+fn normalize_projection(projection: &Document) -> Document {
+    projection
+        .iter()
+        .map(|(key, value)| {
+            let value = match value {
+                Bson::Document(doc) => Bson::Document(normalize_projection(doc)),
+                Bson::Boolean(val) => Bson::Int32(*val as i32),
+                Bson::Int32(val) => Bson::Int32((*val != 0) as i32),
+                Bson::Int64(val) => Bson::Int32((*val != 0) as i32),
+                Bson::Double(val) => Bson::Int32((*val != 0.0) as i32),
+                other => other.clone(),
+            };
+            (key.clone(), value)
+        })
+        .collect()
 }
 
 /// Build a mapping of index names to their index models.
@@ -385,12 +2108,16 @@ fn build_index_map(list_index: Document) -> HashMap<String, IndexModel> {
             }
         })
         .fold(HashMap::new(), |mut acc, doc| {
-            // Extract document keys & generate index name based on keys.
+            // Extract document keys & use the index's actual name, falling back to a name
+            // generated from its keys.
             let idx_keys = match doc.get_document("key").ok() {
                 Some(idx_keys) => idx_keys,
                 None => return acc,
             };
-            let index_name = generate_index_name_from_keys(idx_keys);
+            let index_name = match doc.get_str("name") {
+                Ok(name) => name.to_string(),
+                Err(_) => generate_index_name_from_keys(idx_keys),
+            };
 
             // Build index model, filtering out blacklisted keys.
             let mut options = Document::new();
@@ -409,14 +2136,98 @@ fn build_index_map(list_index: Document) -> HashMap<String, IndexModel> {
 
 async fn sync_model_indexes<'a, T>(
     db: &'a Database, coll: &'a Collection<T>, model_indexes: Vec<IndexModel>, current_indexes_map: HashMap<String, IndexModel>,
+    options: &'a SyncOptions,
 ) -> Result<()> {
-    log::info!("Synchronizing indexes for '{}'.", coll.namespace());
+    info!("Synchronizing indexes for '{}'.", coll.namespace());
 
     // Build a mapping of aspired indexes based on the model's declared indexes.
-    let aspired_indexes_map = model_indexes.iter().fold(HashMap::new(), |mut acc, model| {
+    let aspired_indexes_map = build_aspired_indexes_map(&model_indexes)?;
+
+    let (mut indexes_to_drop, mut indexes_to_create) = diff_indexes(&aspired_indexes_map, &current_indexes_map);
+    if options.adopt_renamed_indexes {
+        for (aspired, existing) in adopt_renamed_indexes(&mut indexes_to_drop, &mut indexes_to_create, &current_indexes_map) {
+            info!(
+                "Adopting existing index '{}' on '{}' in place of '{}'.",
+                existing,
+                coll.namespace(),
+                aspired
+            );
+        }
+    }
+
+    // Drop indexes which have been flagged for dropping.
+    if options.log_progress {
+        info!(
+            "Dropping {} indexes on '{}': {:?}.",
+            indexes_to_drop.len(),
+            coll.namespace(),
+            indexes_to_drop
+        );
+    }
+    for index_name in indexes_to_drop {
+        let drop_command = doc! {
+            "dropIndexes": coll.name(),
+            "index": &index_name,
+        };
+        db.run_command(drop_command, None).await?;
+        if options.log_progress {
+            info!("Dropped index '{}' on '{}'.", index_name, coll.namespace());
+        }
+    }
+
+    // Create any indexes which have been flagged for creation.
+    if options.log_progress {
+        let names: Vec<_> = indexes_to_create.keys().collect();
+        info!("Creating {} indexes on '{}': {:?}.", names.len(), coll.namespace(), names);
+    }
+
+    if !indexes_to_create.is_empty() {
+        create_indexes(db, coll, indexes_to_create.into_values(), options.commit_quorum.as_ref()).await?;
+        if options.log_progress {
+            info!("Created indexes on '{}'.", coll.namespace());
+        }
+    }
+
+    info!("Synchronized indexes for '{}'.", coll.namespace());
+
+    Ok(())
+}
+
+/// Ensure the given indexes exist on the collection, creating any which are missing.
+///
+/// Indexes are matched on their effective name only. Unlike `sync`, no index is ever dropped or
+/// replaced, so an existing index of the same name is left as is even if its spec differs.
+pub(crate) async fn ensure_indexes<T>(db: &Database, coll: &Collection<T>, indexes: Vec<IndexModel>) -> Result<()> {
+    if indexes.is_empty() {
+        return Ok(());
+    }
+    let aspired_indexes_map = build_aspired_indexes_map(&indexes)?;
+    let current_indexes_map = get_current_indexes(db, coll).await?;
+    let missing: BTreeMap<_, _> = aspired_indexes_map
+        .into_iter()
+        .filter(|(name, _)| !current_indexes_map.contains_key(name))
+        .collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+    info!(
+        "Creating {} missing indexes on '{}': {:?}.",
+        missing.len(),
+        coll.namespace(),
+        missing.keys().collect::<Vec<_>>()
+    );
+    create_indexes(db, coll, missing.into_values(), None).await
+}
+
+/// Build a mapping of the given indexes keyed on their effective index name.
+///
+/// The effective name is the explicit `name` option when present, else the key-derived name;
+/// each returned index model carries that name in its options.
+fn build_aspired_indexes_map(indexes: &[IndexModel]) -> Result<HashMap<String, IndexModel>> {
+    let mut aspired_indexes_map = HashMap::new();
+    for model in indexes.iter() {
         let mut target_model = model.clone();
-        // Populate the 'target' indexes map for easy comparison later.
-        let key = generate_index_name_from_keys(&model.keys);
+        let key = index_name(model);
 
         // Ensure we have an options object with at least the index name.
         match &mut target_model.options {
@@ -431,15 +2242,145 @@ async fn sync_model_indexes<'a, T>(
                 target_model.options = Some(options);
             }
         }
-        acc.insert(key, target_model);
+        // Two declared indexes which map to the same name can never both be created.
+        if aspired_indexes_map.contains_key(&key) {
+            return Err(WitherError::DuplicateIndexName(key));
+        }
+        aspired_indexes_map.insert(key, target_model);
+    }
+    Ok(aspired_indexes_map)
+}
+
+/// Create the given indexes on the collection with a single `createIndexes` command.
+async fn create_indexes<T>(
+    db: &Database, coll: &Collection<T>, indexes: impl IntoIterator<Item = IndexModel>, commit_quorum: Option<&Bson>,
+) -> Result<()> {
+    let indexes: Vec<_> = indexes.into_iter().collect();
+    let requested: Vec<_> = indexes
+        .iter()
+        .map(|index| (index_name(index), index.keys.clone()))
+        .collect();
+    let indexes = indexes.into_iter().fold(vec![], |mut acc, index_model| {
+        let mut index_doc = Document::new();
+        index_doc.insert("key", index_model.keys);
+        if let Some(options) = index_model.options {
+            index_doc.extend(options);
+        }
+        acc.push(index_doc);
         acc
     });
+    let mut create_command = doc! {
+        "createIndexes": coll.name(),
+        "indexes": indexes,
+    };
+    if let Some(commit_quorum) = commit_quorum {
+        create_command.insert("commitQuorum", commit_quorum.clone());
+    }
+    let err = match db.run_command(create_command, None).await {
+        Ok(_) => return Ok(()),
+        Err(err) => WitherError::from(err),
+    };
+    // Name the conflicting indexes, as the server's error does not identify the requested one.
+    if err.is_index_conflict() {
+        let current_indexes_map = get_current_indexes(db, coll).await?;
+        if let Some((aspired, existing)) = find_index_name_conflict(&requested, &current_indexes_map) {
+            return Err(WitherError::IndexNameConflict { aspired, existing });
+        }
+    }
+    Err(err)
+}
+
+/// The effective name of the given index, being its explicit `name` option when present, else
+/// its key-derived name.
+fn index_name(index: &IndexModel) -> String {
+    match index.options.as_ref().and_then(|options| options.get_str("name").ok()) {
+        Some(name) => name.to_string(),
+        None => generate_index_name_from_keys(&index.keys),
+    }
+}
+
+/// Find a requested index whose keys are already covered by a current index of another name,
+/// returning the names of both.
+///
+/// The default index on `_id` is never part of the current indexes, so it is checked for
+/// explicitly.
+fn find_index_name_conflict(requested: &[(String, Document)], current_indexes_map: &HashMap<String, IndexModel>) -> Option<(String, String)> {
+    let (id_index_name, id_index) = (MONGO_ID_INDEX_NAME.to_string(), IndexModel::new(doc! {"_id": 1}, None));
+    let mut current: Vec<_> = current_indexes_map.iter().collect();
+    current.sort_by(|a, b| a.0.cmp(b.0));
+    requested.iter().find_map(|(aspired, keys)| {
+        std::iter::once((&id_index_name, &id_index))
+            .chain(current.iter().copied())
+            .find(|(name, index)| *name != aspired && &index.keys == keys)
+            .map(|(existing, _)| (aspired.clone(), existing.clone()))
+    })
+}
+
+/// Remove the indexes to create which are covered by an index flagged for dropping, having the
+/// same keys & options under a different name, from both lists, so the current index is kept.
+///
+/// Returns the pairs of aspired & adopted index names.
+fn adopt_renamed_indexes(
+    indexes_to_drop: &mut Vec<String>, indexes_to_create: &mut BTreeMap<String, IndexModel>, current_indexes_map: &HashMap<String, IndexModel>,
+) -> Vec<(String, String)> {
+    let unnamed_options = |index: &IndexModel| {
+        normalize_index_options(&index.options).map(|mut options| {
+            options.remove("name");
+            options
+        })
+    };
+    let mut adopted = vec![];
+    for (aspired_name, aspired_index) in indexes_to_create.iter() {
+        let position = indexes_to_drop
+            .iter()
+            .position(|name| match current_indexes_map.get(name) {
+                Some(current_index) => {
+                    name != aspired_name
+                        && current_index.keys == aspired_index.keys
+                        && unnamed_options(current_index) == unnamed_options(aspired_index)
+                }
+                None => false,
+            });
+        if let Some(position) = position {
+            adopted.push((aspired_name.clone(), indexes_to_drop.remove(position)));
+        }
+    }
+    for (aspired_name, _) in adopted.iter() {
+        indexes_to_create.remove(aspired_name);
+    }
+    adopted
+}
+
+/// Build a typed summary of the given index, as reported by the server.
+fn index_info_from_model(name: String, model: &IndexModel) -> IndexInfo {
+    let options = model.options.clone().unwrap_or_default();
+    let ttl = match options.get("expireAfterSeconds") {
+        Some(Bson::Int32(secs)) => u64::try_from(*secs).ok(),
+        Some(Bson::Int64(secs)) => u64::try_from(*secs).ok(),
+        Some(Bson::Double(secs)) if *secs >= 0.0 => Some(*secs as u64),
+        _ => None,
+    };
+    IndexInfo {
+        name,
+        keys: model.keys.clone(),
+        unique: options.get_bool("unique").unwrap_or(false),
+        ttl: ttl.map(std::time::Duration::from_secs),
+        background: options.get_bool("background").unwrap_or(false),
+    }
+}
 
+/// Diff the aspired indexes of a model against the current indexes of its collection.
+///
+/// Returns the names of the indexes to drop & the indexes to create, both ordered by index name
+/// so that the commands issued by `sync` are deterministic.
+fn diff_indexes(
+    aspired_indexes_map: &HashMap<String, IndexModel>, current_indexes_map: &HashMap<String, IndexModel>,
+) -> (Vec<String>, BTreeMap<String, IndexModel>) {
     // For any current index which does not exist in the model's aspired indexes
     // list, add it to the drop list.
-    let mut indexes_to_drop = current_indexes_map.iter().fold(vec![], |mut acc, (key, _)| {
+    let mut indexes_to_drop = current_indexes_map.keys().fold(vec![], |mut acc, key| {
         if !aspired_indexes_map.contains_key(key) {
-            acc.push(key);
+            acc.push(key.clone());
         }
         acc
     });
@@ -447,7 +2388,7 @@ async fn sync_model_indexes<'a, T>(
     // Diff aspired indexes with current indexes, and update our lists of indexes to create and
     // drop based on diffing the options of each index model. This is based purely on the
     // implementation of PartialEq on the bson::Document type.
-    let mut indexes_to_create: HashMap<String, IndexModel> = HashMap::new();
+    let mut indexes_to_create: BTreeMap<String, IndexModel> = BTreeMap::new();
     for (aspired_index_name, aspired_index) in aspired_indexes_map.iter() {
         // Unpack the corresponding current index by name if it exists, else prep it for creation.
         let current_index = match current_indexes_map.get(aspired_index_name) {
@@ -462,43 +2403,212 @@ async fn sync_model_indexes<'a, T>(
 
         // If the options of the two index models do not match, then we need to drop the existing
         // and create an updated version.
-        if aspired_index.options != current_index.options {
-            indexes_to_drop.push(aspired_index_name);
+        if normalize_index_options(&aspired_index.options) != normalize_index_options(&current_index.options) {
+            indexes_to_drop.push(aspired_index_name.clone());
             indexes_to_create.insert(aspired_index_name.clone(), aspired_index.clone());
         }
     }
 
-    // Drop indexes which have been flagged for dropping.
-    for index_name in indexes_to_drop {
-        let drop_command = doc! {
-            "dropIndexes": coll.name(),
-            "index": index_name,
-        };
-        db.run_command(drop_command, None).await?;
-    }
+    // Order drops by name, as creates are already ordered by the map.
+    indexes_to_drop.sort();
+    (indexes_to_drop, indexes_to_create)
+}
 
-    // Create any indexes which have been flagged for creation.
-    let indexes_to_create = indexes_to_create.into_iter().fold(vec![], |mut acc, (_, index_model)| {
-        let mut index_doc = Document::new();
-        index_doc.insert("key", index_model.keys);
-        if let Some(options) = index_model.options {
-            index_doc.extend(options);
-        }
-        acc.push(index_doc);
-        acc
-    });
-    if !indexes_to_create.is_empty() {
-        db.run_command(
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_documents_should_produce_minimal_set_and_unset() {
+        let before = doc! {"_id": 1, "a": 1, "b": "x", "gone": true, "nested": {"x": 1, "y": 2}, "tags": ["a"]};
+        let after = doc! {"_id": 1, "a": 2, "b": "x", "nested": {"x": 1, "y": 3, "z": 4}, "tags": ["a", "b"], "new": null};
+        assert_eq!(
+            diff_documents(&before, &after),
             doc! {
-                "createIndexes": coll.name(),
-                "indexes": indexes_to_create,
-            },
-            None,
-        )
-        .await?;
+                "$set": {"a": 2, "nested.y": 3, "nested.z": 4, "tags": ["a", "b"], "new": null},
+                "$unset": {"gone": ""},
+            }
+        );
     }
 
-    log::info!("Synchronized indexes for '{}'.", coll.namespace());
+    #[test]
+    fn diff_documents_should_handle_unchanged_and_emptied_subdocuments() {
+        let before = doc! {"a": 1, "nested": {"x": 1}, "other": {"y": 1}};
+        assert_eq!(diff_documents(&before, &before), doc! {});
+        let after = doc! {"a": 1, "nested": {}, "other": {}};
+        assert_eq!(diff_documents(&before, &after), doc! {"$set": {"nested": {}, "other": {}}});
+        let after = doc! {"a": 1, "nested": 5, "other": {"y": 1}};
+        assert_eq!(diff_documents(&before, &after), doc! {"$set": {"nested": 5}});
+    }
 
-    Ok(())
+    #[test]
+    fn generate_index_name_from_keys_should_handle_wildcard_keys() {
+        assert_eq!(generate_index_name_from_keys(&doc! {"$**": 1}), "$**_1");
+        assert_eq!(generate_index_name_from_keys(&doc! {"tags.$**": 1}), "tags.$**_1");
+    }
+
+    #[test]
+    fn generate_index_name_from_keys_should_handle_non_i32_directions() {
+        assert_eq!(generate_index_name_from_keys(&doc! {"a": 1i64, "b": -1.0}), "a_1_b_-1");
+    }
+
+    #[test]
+    fn generate_index_name_from_keys_should_handle_dotted_compound_keys() {
+        assert_eq!(generate_index_name_from_keys(&doc! {"a.b": 1, "c": -1}), "a.b_1_c_-1");
+        assert_eq!(
+            generate_index_name_from_keys(&doc! {"addr.city": 1, "addr.zip": 1}),
+            "addr.city_1_addr.zip_1"
+        );
+    }
+
+    #[test]
+    fn generate_index_name_from_keys_should_handle_string_values() {
+        assert_eq!(generate_index_name_from_keys(&doc! {"title": "text"}), "title_text");
+        assert_eq!(generate_index_name_from_keys(&doc! {"loc": "2dsphere", "a": 1}), "loc_2dsphere_a_1");
+        assert_eq!(generate_index_name_from_keys(&doc! {"id": "hashed"}), "id_hashed");
+    }
+
+    #[test]
+    fn generate_index_name_from_keys_should_handle_fractional_directions() {
+        assert_eq!(generate_index_name_from_keys(&doc! {"a": 1.5}), "a_1.5");
+    }
+
+    #[test]
+    fn diff_indexes_should_order_changes_by_name() {
+        let index = |name: &str, unique: bool| IndexModel::new(doc! {name: 1}, Some(doc! {"name": name, "unique": unique}));
+        let aspired: HashMap<_, _> = ["c", "a", "e", "b"]
+            .iter()
+            .map(|name| (name.to_string(), index(name, true)))
+            .collect();
+        let current: HashMap<_, _> = ["d", "b", "f", "a"]
+            .iter()
+            .map(|name| (name.to_string(), index(name, false)))
+            .collect();
+
+        let (to_drop, to_create) = diff_indexes(&aspired, &current);
+
+        assert_eq!(to_drop, vec!["a", "b", "d", "f"]);
+        assert_eq!(to_create.keys().collect::<Vec<_>>(), vec!["a", "b", "c", "e"]);
+    }
+
+    #[test]
+    fn adopt_renamed_indexes_should_keep_matching_current_indexes() {
+        let aspired: HashMap<_, _> = vec![
+            (
+                "a_1".to_string(),
+                IndexModel::new(doc! {"a": 1}, Some(doc! {"name": "a_1", "unique": true})),
+            ),
+            (
+                "b_1".to_string(),
+                IndexModel::new(doc! {"b": 1}, Some(doc! {"name": "b_1", "unique": true})),
+            ),
+        ]
+        .into_iter()
+        .collect();
+        let current: HashMap<_, _> = vec![
+            (
+                "custom-a".to_string(),
+                IndexModel::new(doc! {"a": 1}, Some(doc! {"name": "custom-a", "unique": true})),
+            ),
+            ("custom-b".to_string(), IndexModel::new(doc! {"b": 1}, Some(doc! {"name": "custom-b"}))),
+        ]
+        .into_iter()
+        .collect();
+        let (mut to_drop, mut to_create) = diff_indexes(&aspired, &current);
+
+        let adopted = adopt_renamed_indexes(&mut to_drop, &mut to_create, &current);
+
+        assert_eq!(adopted, vec![("a_1".to_string(), "custom-a".to_string())]);
+        assert_eq!(to_drop, vec!["custom-b"]);
+        assert_eq!(to_create.keys().collect::<Vec<_>>(), vec!["b_1"]);
+    }
+
+    #[test]
+    fn find_index_name_conflict_should_match_keys_under_other_names() {
+        let current: HashMap<_, _> = vec![("custom-a".to_string(), IndexModel::new(doc! {"a": 1}, None))]
+            .into_iter()
+            .collect();
+        let conflict = |name: &str, keys: Document| find_index_name_conflict(&[(name.to_string(), keys)], &current);
+
+        assert_eq!(conflict("a_1", doc! {"a": 1}), Some(("a_1".to_string(), "custom-a".to_string())));
+        assert_eq!(conflict("id", doc! {"_id": 1}), Some(("id".to_string(), "_id_".to_string())));
+        assert_eq!(conflict("custom-a", doc! {"a": 1}), None);
+        assert_eq!(conflict("a_-1", doc! {"a": -1}), None);
+    }
+
+    #[test]
+    fn index_info_from_model_should_extract_known_options() {
+        let ttl_index = IndexModel::new(
+            doc! {"created": 1},
+            Some(doc! {"name": "created_1", "unique": true, "expireAfterSeconds": 3600_i64, "background": true}),
+        );
+        let plain_index = IndexModel::new(doc! {"email": -1}, Some(doc! {"name": "email_-1"}));
+
+        let ttl_info = index_info_from_model("created_1".into(), &ttl_index);
+        let plain_info = index_info_from_model("email_-1".into(), &plain_index);
+
+        assert_eq!(ttl_info.keys, doc! {"created": 1});
+        assert!(ttl_info.unique && ttl_info.background);
+        assert_eq!(ttl_info.ttl, Some(std::time::Duration::from_secs(3600)));
+        assert_eq!(plain_info.name, "email_-1");
+        assert!(!plain_info.unique && !plain_info.background);
+        assert_eq!(plain_info.ttl, None);
+    }
+
+    #[test]
+    fn missing_fields_should_only_include_absent_top_level_keys() {
+        let original = doc! {"_id": 1, "a": 1, "nested": {"x": 1}};
+        let upgraded = doc! {"_id": 1, "a": 2, "nested": {"x": 1, "y": 2}, "b": "default", "c": []};
+
+        assert_eq!(missing_fields(&original, &upgraded), doc! {"b": "default", "c": []});
+        assert!(missing_fields(&upgraded, &original).is_empty());
+    }
+
+    #[test]
+    fn capped_matches_should_accept_rounded_sizes_only() {
+        let declared = CappedOptions::new(1000, Some(10));
+
+        assert!(capped_matches(declared, Some(CappedOptions::new(1000, Some(10)))));
+        assert!(capped_matches(declared, Some(CappedOptions::new(1024, Some(10)))));
+        assert!(!capped_matches(declared, Some(CappedOptions::new(2048, Some(10)))));
+        assert!(!capped_matches(declared, Some(CappedOptions::new(1000, None))));
+        assert!(!capped_matches(declared, None));
+    }
+
+    #[test]
+    fn normalize_index_options_should_treat_hidden_false_as_absent() {
+        let aspired = Some(doc! {"name": "i_1", "hidden": false});
+        let current = Some(doc! {"name": "i_1"});
+        assert_eq!(normalize_index_options(&aspired), normalize_index_options(&current));
+    }
+
+    #[test]
+    fn normalize_index_options_should_normalize_wildcard_projections() {
+        let aspired = Some(doc! {"name": "$**_1", "wildcardProjection": {"a": 1, "b": {"c": 1i64}}});
+        let current = Some(doc! {"name": "$**_1", "wildcardProjection": {"a": true, "b": {"c": true}, "_id": false}});
+        assert_eq!(normalize_index_options(&aspired), normalize_index_options(&current));
+        let changed = Some(doc! {"name": "$**_1", "wildcardProjection": {"a": false, "b": {"c": true}}});
+        assert_ne!(normalize_index_options(&aspired), normalize_index_options(&changed));
+    }
+
+    #[test]
+    fn normalize_index_options_should_ignore_text_index_version() {
+        let aspired = Some(doc! {"name": "title_text", "weights": {"title": 1}});
+        let current = Some(doc! {"name": "title_text", "weights": {"title": 1}, "textIndexVersion": 3});
+        assert_eq!(normalize_index_options(&aspired), normalize_index_options(&current));
+    }
+
+    #[test]
+    fn normalize_index_options_should_ignore_background() {
+        let aspired = Some(doc! {"name": "i_1", "background": true});
+        let current = Some(doc! {"name": "i_1"});
+        assert_eq!(normalize_index_options(&aspired), normalize_index_options(&current));
+    }
+
+    #[test]
+    fn normalize_index_options_should_preserve_hidden_true() {
+        let aspired = Some(doc! {"name": "i_1", "hidden": true});
+        let current = Some(doc! {"name": "i_1"});
+        assert_ne!(normalize_index_options(&aspired), normalize_index_options(&current));
+    }
 }
@@ -0,0 +1,82 @@
+//! Transaction related code.
+
+use std::time::{Duration, Instant};
+
+use futures::future::BoxFuture;
+use mongodb::error::{TRANSIENT_TRANSACTION_ERROR, UNKNOWN_TRANSACTION_COMMIT_RESULT};
+use mongodb::{Client, ClientSession};
+
+use crate::error::{Result, WitherError};
+
+/// The time after which a failed transaction is no longer retried, as recommended by the driver
+/// specifications.
+const TRANSACTION_RETRY_TIME_LIMIT: Duration = Duration::from_secs(120);
+
+/// Execute the given callback within a transaction, committing it once the callback succeeds.
+///
+/// A session is started on the given client, and the callback is called with the session after
+/// a transaction has been started on it; pass the session to the `*_with_session` methods, such
+/// as `Model::save_with_session`, to have them participate in the transaction. When the callback
+/// returns an error, the transaction is aborted. Following the driver's callback-based
+/// transaction pattern, the whole transaction is retried when it fails with a
+/// `TransientTransactionError`, and the commit alone is retried when its result is unknown, for
+/// up to 120 seconds; as such, the callback may be called more than once.
+///
+/// As the callback's future borrows the session, it must be boxed, EG:
+///
+/// ```ignore
+/// let user = wither::with_transaction(&client, |session| {
+///     let db = db.clone();
+///     Box::pin(async move {
+///         let mut user = User { id: None, email: "a@b.com".into() };
+///         user.save_with_session(&db, session, None).await?;
+///         Ok(user)
+///     })
+/// })
+/// .await?;
+/// ```
+pub async fn with_transaction<F, T>(client: &Client, mut f: F) -> Result<T>
+where
+    F: for<'a> FnMut(&'a mut ClientSession) -> BoxFuture<'a, Result<T>>,
+{
+    let mut session = client.start_session(None).await?;
+    let started = Instant::now();
+    'transaction: loop {
+        session.start_transaction(None).await?;
+        let value = match f(&mut session).await {
+            Ok(value) => value,
+            Err(err) => {
+                // The callback may have already ended the transaction, in which case this errors.
+                let _ = session.abort_transaction().await;
+                if has_error_label(&err, TRANSIENT_TRANSACTION_ERROR) && started.elapsed() < TRANSACTION_RETRY_TIME_LIMIT {
+                    continue 'transaction;
+                }
+                return Err(err);
+            }
+        };
+        loop {
+            let err = match session.commit_transaction().await {
+                Ok(()) => return Ok(value),
+                Err(err) => WitherError::from(err),
+            };
+            if started.elapsed() >= TRANSACTION_RETRY_TIME_LIMIT {
+                return Err(err);
+            }
+            if has_error_label(&err, UNKNOWN_TRANSACTION_COMMIT_RESULT) {
+                continue;
+            }
+            if has_error_label(&err, TRANSIENT_TRANSACTION_ERROR) {
+                continue 'transaction;
+            }
+            return Err(err);
+        }
+    }
+}
+
+/// Whether the given error is a driver error carrying the given label.
+fn has_error_label(err: &WitherError, label: &str) -> bool {
+    match err {
+        WitherError::Mongo(err) => err.contains_label(label),
+        _ => false,
+    }
+}
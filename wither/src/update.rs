@@ -0,0 +1,107 @@
+use crate::bson::{Bson, Document};
+use crate::mongodb::options::UpdateModifications;
+
+/// A builder of update documents, for use with `Model::update`, `Model::find_one_and_update` &
+/// the like.
+///
+/// Each operation is accumulated under its corresponding update operator. Calling an operation
+/// more than once for the same field replaces the previous value for that field.
+///
+/// ```
+/// # use wither::bson::doc;
+/// # use wither::UpdateBuilder;
+/// let update = UpdateBuilder::new().set("email", "x").inc("login_count", 1).build();
+/// assert_eq!(update, doc!{"$set": {"email": "x"}, "$inc": {"login_count": 1}});
+/// ```
+///
+/// For anything not covered here, such as pipeline updates or other operators, construct the
+/// update document directly.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UpdateBuilder {
+    set: Document,
+    unset: Document,
+    inc: Document,
+    push: Document,
+}
+
+impl UpdateBuilder {
+    /// Construct a new instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the given field to the given value, via `$set`.
+    pub fn set(mut self, field: impl Into<String>, value: impl Into<Bson>) -> Self {
+        self.set.insert(field, value);
+        self
+    }
+
+    /// Remove the given field, via `$unset`.
+    pub fn unset(mut self, field: impl Into<String>) -> Self {
+        self.unset.insert(field, "");
+        self
+    }
+
+    /// Increment the given field by the given amount, via `$inc`.
+    pub fn inc(mut self, field: impl Into<String>, amount: impl Into<Bson>) -> Self {
+        self.inc.insert(field, amount);
+        self
+    }
+
+    /// Append the given value to the array at the given field, via `$push`.
+    pub fn push(mut self, field: impl Into<String>, value: impl Into<Bson>) -> Self {
+        self.push.insert(field, value);
+        self
+    }
+
+    /// Whether no operations have been added to this builder.
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty() && self.unset.is_empty() && self.inc.is_empty() && self.push.is_empty()
+    }
+
+    /// Build the final update document, omitting any operators which have no fields.
+    pub fn build(self) -> Document {
+        let mut update = Document::new();
+        for (operator, fields) in [("$set", self.set), ("$unset", self.unset), ("$inc", self.inc), ("$push", self.push)] {
+            if !fields.is_empty() {
+                update.insert(operator, fields);
+            }
+        }
+        update
+    }
+}
+
+impl From<UpdateBuilder> for Document {
+    fn from(builder: UpdateBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<UpdateBuilder> for UpdateModifications {
+    fn from(builder: UpdateBuilder) -> Self {
+        UpdateModifications::Document(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bson::doc;
+
+    #[test]
+    fn update_builder_should_group_operations_by_operator() {
+        let update = UpdateBuilder::new()
+            .set("email", "x")
+            .unset("legacy")
+            .inc("login_count", 1)
+            .push("tags", "new")
+            .set("email", "y")
+            .build();
+        assert_eq!(
+            update,
+            doc! {"$set": {"email": "y"}, "$unset": {"legacy": ""}, "$inc": {"login_count": 1}, "$push": {"tags": "new"}}
+        );
+        assert!(UpdateBuilder::new().is_empty());
+        assert_eq!(UpdateBuilder::new().build(), doc! {});
+    }
+}
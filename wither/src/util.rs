@@ -0,0 +1,47 @@
+//! Utilities for working with BSON alongside common Rust types.
+
+use chrono::{LocalResult, TimeZone, Utc};
+
+use crate::bson;
+
+/// Convert the given `chrono` datetime into a BSON datetime.
+///
+/// BSON datetimes are stored by the server as milliseconds since the Unix epoch, in UTC, so any
+/// sub-millisecond precision is truncated. This is exactly what is stored when a `chrono`
+/// datetime is used in a filter or as a field value.
+///
+/// ```
+/// # use chrono::TimeZone;
+/// # use wither::util::{from_bson_datetime, to_bson_datetime};
+/// let dt = chrono::Utc.ymd(2021, 11, 20).and_hms_micro(22, 37, 34, 123_456);
+/// let bson_dt = to_bson_datetime(dt);
+/// assert_eq!(bson_dt.timestamp_millis(), dt.timestamp_millis());
+/// assert_eq!(from_bson_datetime(bson_dt), chrono::Utc.ymd(2021, 11, 20).and_hms_milli(22, 37, 34, 123));
+/// ```
+pub fn to_bson_datetime(dt: chrono::DateTime<Utc>) -> bson::DateTime {
+    bson::DateTime::from_millis(dt.timestamp_millis())
+}
+
+/// Convert the given BSON datetime into a `chrono` datetime, in UTC.
+///
+/// BSON datetimes which are out of the range supported by `chrono` are clamped to
+/// `chrono::MIN_DATETIME` or `chrono::MAX_DATETIME`.
+pub fn from_bson_datetime(dt: bson::DateTime) -> chrono::DateTime<Utc> {
+    match Utc.timestamp_millis_opt(dt.timestamp_millis()) {
+        LocalResult::Single(dt) => dt,
+        _ if dt.timestamp_millis() < 0 => chrono::MIN_DATETIME,
+        _ => chrono::MAX_DATETIME,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_bson_datetime_should_clamp_out_of_range_values() {
+        assert_eq!(from_bson_datetime(bson::DateTime::from_millis(i64::MIN)), chrono::MIN_DATETIME);
+        assert_eq!(from_bson_datetime(bson::DateTime::from_millis(i64::MAX)), chrono::MAX_DATETIME);
+        assert_eq!(from_bson_datetime(bson::DateTime::from_millis(0)), Utc.timestamp(0, 0));
+    }
+}
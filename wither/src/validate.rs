@@ -0,0 +1,10 @@
+use crate::error::Result;
+
+/// A trait describing a model which can validate its own state.
+///
+/// Implement this trait on a `Model` to use `Model::save_validated`, which will reject invalid
+/// instances before any round-trip to the database is made.
+pub trait Validate {
+    /// Validate this instance, returning `WitherError::Validation` if it is invalid.
+    fn validate(&self) -> Result<()>;
+}
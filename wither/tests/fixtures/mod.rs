@@ -1,5 +1,3 @@
-#![cfg(not(feature = "sync"))]
-
 pub mod models;
 
 use std::env;
@@ -9,6 +7,7 @@ use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
 use wither::bson::doc;
 use wither::bson::oid::ObjectId;
+use wither::mongodb::options::{Acknowledgment, WriteConcern};
 use wither::mongodb::{Client, Database};
 use wither::prelude::*;
 
@@ -21,7 +20,7 @@ lazy_static! {
 //////////////////////////////////////////////////////////////////////////////
 // User //////////////////////////////////////////////////////////////////////
 
-#[derive(Model, Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[derive(Model, Serialize, Deserialize, Debug, Clone, Default, Eq, PartialEq)]
 #[model(collection_name = "users")]
 #[model(index(
     keys = r#"doc!{"email": 1}"#,
@@ -46,11 +45,30 @@ impl Migrating for User {
                 filter: doc! {"email": doc!{"$exists": true}},
                 set: Some(doc! {"testfield": "test"}),
                 unset: None,
+                batch_size: None,
+                write_concern: None,
+            }),
+            // Derive a field from another field of the document.
+            Box::new(wither::PipelineMigration {
+                name: String::from("test-pipeline-migration"),
+                threshold: chrono::Utc.ymd(2100, 1, 1).and_hms(1, 0, 0),
+                filter: doc! {"email": doc!{"$exists": true}},
+                pipeline: vec![doc! {"$set": doc!{"testpipelinefield": "$email"}}],
+                write_concern: Some(WriteConcern::builder().w(Some(Acknowledgment::Nodes(1))).build()),
             }),
         ]
     }
 }
 
+impl Validate for User {
+    fn validate(&self) -> wither::Result<()> {
+        if self.email.is_empty() {
+            return Err(wither::WitherError::Validation("email must not be empty".to_string()));
+        }
+        Ok(())
+    }
+}
+
 //////////////////////////////////////////////////////////////////////////////
 // UserModelBadMigrations ////////////////////////////////////////////////////
 
@@ -79,11 +97,215 @@ impl Migrating for UserModelBadMigrations {
                 filter: doc! {"email": doc!{"$exists": true}},
                 set: None,
                 unset: None,
+                batch_size: None,
+                write_concern: None,
             }),
         ]
     }
 }
 
+//////////////////////////////////////////////////////////////////////////////
+// UserModelIndexedMigrations ////////////////////////////////////////////////
+
+#[derive(Model, Serialize, Deserialize, Debug, Clone)]
+#[model(collection_name = "users_indexed_migrations")]
+pub struct UserModelIndexedMigrations {
+    /// The user's unique ID.
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+
+    /// The user's unique email.
+    pub email: String,
+}
+
+impl Migrating for UserModelIndexedMigrations {
+    fn migrations() -> Vec<Box<dyn wither::Migration<Self>>> {
+        vec![Box::new(IndexedMigration(wither::IntervalMigration {
+            name: String::from("test-indexed-migration"),
+            threshold: chrono::Utc.ymd(2100, 1, 1).and_hms(1, 0, 0),
+            filter: doc! {"email": doc!{"$exists": true}},
+            set: Some(doc! {"testfield": "test"}),
+            unset: None,
+            batch_size: None,
+            write_concern: None,
+        }))]
+    }
+}
+
+/// An `IntervalMigration` which requires an index covering its filter.
+pub struct IndexedMigration(wither::IntervalMigration);
+
+#[wither::async_trait]
+impl<T: Send + Sync> wither::Migration<T> for IndexedMigration {
+    async fn execute<'c>(&self, coll: &'c wither::mongodb::Collection<T>) -> wither::Result<wither::MigrationReport> {
+        wither::Migration::<T>::execute(&self.0, coll).await
+    }
+
+    async fn execute_forced<'c>(&self, coll: &'c wither::mongodb::Collection<T>) -> wither::Result<wither::MigrationReport> {
+        wither::Migration::<T>::execute_forced(&self.0, coll).await
+    }
+
+    fn required_indexes(&self) -> Vec<wither::IndexModel> {
+        vec![wither::IndexModel::new(doc! {"email": 1}, Some(doc! {"name": "migration-email"}))]
+    }
+
+    async fn status<'c>(&self, coll: &'c wither::mongodb::Collection<T>) -> wither::Result<wither::MigrationStatus> {
+        wither::Migration::<T>::status(&self.0, coll).await
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// UserModelExpiredIndexedMigrations /////////////////////////////////////////
+
+#[derive(Model, Serialize, Deserialize, Debug, Clone)]
+#[model(collection_name = "users_expired_indexed_migrations")]
+pub struct UserModelExpiredIndexedMigrations {
+    /// The user's unique ID.
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+
+    /// The user's unique email.
+    pub email: String,
+}
+
+impl Migrating for UserModelExpiredIndexedMigrations {
+    fn migrations() -> Vec<Box<dyn wither::Migration<Self>>> {
+        vec![Box::new(IndexedMigration(wither::IntervalMigration {
+            name: String::from("test-expired-indexed-migration"),
+            threshold: chrono::Utc.ymd(2000, 1, 1).and_hms(1, 0, 0),
+            filter: doc! {"email": doc!{"$exists": true}},
+            set: Some(doc! {"testfield": "test"}),
+            unset: None,
+            batch_size: None,
+            write_concern: None,
+        }))]
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// VersionedUser /////////////////////////////////////////////////////////////
+
+#[derive(Model, Serialize, Deserialize, Debug, Clone, Default, Eq, PartialEq)]
+#[model(collection_name = "versioned_users", version_field = "version")]
+pub struct VersionedUser {
+    /// The user's unique ID.
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+
+    /// The user's unique email.
+    pub email: String,
+
+    /// The version of this document, incremented on each versioned save.
+    pub version: i64,
+
+    /// The user's login count, which is maintained by another service.
+    #[model(no_overwrite)]
+    pub logins: i32,
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// ReadOnlyUser //////////////////////////////////////////////////////////////
+
+#[derive(Model, Serialize, Deserialize, Debug, Clone, Default, Eq, PartialEq)]
+#[model(collection_name = "users", read_only)]
+pub struct ReadOnlyUser {
+    /// The user's unique ID.
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+
+    /// The user's unique email.
+    pub email: String,
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// DefaultedUser /////////////////////////////////////////////////////////////
+
+#[derive(Model, Serialize, Deserialize, Debug, Clone, Default, Eq, PartialEq)]
+#[model(collection_name = "users")]
+pub struct DefaultedUser {
+    /// The user's unique ID.
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+
+    /// The user's unique email.
+    pub email: String,
+
+    /// The user's login count, which older documents do not have.
+    #[serde(default)]
+    pub logins: i32,
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// TrackedUser ///////////////////////////////////////////////////////////////
+
+#[derive(Model, Serialize, Deserialize, Debug, Clone, Default, Eq, PartialEq)]
+#[model(collection_name = "users")]
+pub struct TrackedUser {
+    /// The user's unique ID.
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+
+    /// The user's unique email.
+    pub email: String,
+
+    /// The user's login count, which is maintained by another service.
+    #[model(no_overwrite)]
+    pub logins: i32,
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// SparseTrackedUser /////////////////////////////////////////////////////////
+
+#[derive(Model, Serialize, Deserialize, Debug, Clone, Default, Eq, PartialEq)]
+#[model(collection_name = "users")]
+pub struct SparseTrackedUser {
+    /// The user's unique ID.
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+
+    /// The user's login count, which is maintained by another service, when known.
+    #[model(no_overwrite)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logins: Option<i32>,
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// SnapshotUser //////////////////////////////////////////////////////////////
+
+#[derive(Model, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[model(collection_name = "users", snapshot_field = "snapshot")]
+pub struct SnapshotUser {
+    /// The user's unique ID.
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+
+    /// The user's unique email.
+    pub email: String,
+
+    /// The snapshot of this user taken when it was last marked clean.
+    #[serde(skip)]
+    pub snapshot: Option<wither::bson::Document>,
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// ValidatedUser /////////////////////////////////////////////////////////////
+
+#[derive(Model, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[model(
+    collection_name = "validated_users",
+    validator = r#"doc!{"$jsonSchema": {"bsonType": "object", "required": ["email"], "properties": {"email": {"bsonType": "string"}}}}"#,
+    validation_level = "moderate"
+)]
+pub struct ValidatedUser {
+    /// The user's unique ID.
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+
+    /// The user's email, which the collection's validator requires.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+}
+
 /// A singular type representing the various fixtures available in this harness.
 ///
 /// This type represents some combination of desired states which this system's dependencies must
@@ -105,12 +327,21 @@ impl Fixture {
         Fixture { client }
     }
 
-    // /// Remove all documents & indexes from the collections of the data models used by this harness.
-    // pub fn with_empty_collections(self) -> Self {
-    //     DB.clone().collection(User::COLLECTION_NAME).drop(None).expect("failed to drop collection");
-    //     DB.clone().collection(UserModelBadMigrations::COLLECTION_NAME).drop(None).expect("failed to
-    // drop collection");     self
-    // }
+    /// Remove all documents & indexes from the collections of the data models used by this harness.
+    pub async fn with_empty_collections(self) -> Self {
+        let db = self.get_db();
+        User::drop_collection(&db).await.expect("failed to drop collection");
+        UserModelBadMigrations::drop_collection(&db)
+            .await
+            .expect("failed to drop collection");
+        UserModelIndexedMigrations::drop_collection(&db)
+            .await
+            .expect("failed to drop collection");
+        VersionedUser::drop_collection(&db)
+            .await
+            .expect("failed to drop collection");
+        self
+    }
 
     /// Drop the database which is used by this harness.
     pub async fn with_dropped_database(self) -> Self {
@@ -133,4 +364,9 @@ impl Fixture {
     pub fn get_db(&self) -> Database {
         self.client.database("witherTestDB")
     }
+
+    /// Get a handle to the client used by this harness.
+    pub fn get_client(&self) -> &Client {
+        &self.client
+    }
 }
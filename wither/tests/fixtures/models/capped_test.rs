@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use wither::bson::oid::ObjectId;
+use wither::prelude::*;
+
+/// Capped V1 is a capped collection with a size & a document limit.
+#[derive(Model, Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[model(collection_name = "cappedTest", capped(size = 4096, max = 10))]
+pub struct CappedTestV1 {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+
+    pub i: String,
+}
+
+/// Capped V2 is the same capped collection with a larger size & no document limit.
+#[derive(Model, Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[model(collection_name = "cappedTest", capped(size = 8192))]
+pub struct CappedTestV2 {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+
+    pub i: String,
+}
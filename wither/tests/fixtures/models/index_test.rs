@@ -66,3 +66,39 @@ pub struct IndexTestV6 {
 
     pub i: String,
 }
+
+/// Index Duplicate declares two indexes which map to the same index name
+///
+/// The keys are spelled differently only to avoid clippy's `duplicated_attributes` lint
+#[derive(Model, Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[model(collection_name = "indexTest")]
+#[model(index(keys = r#"doc!{"i": 1}"#))]
+#[model(index(keys = r#"doc! {"i": 1}"#, options = r#"doc!{"unique": true}"#))]
+pub struct IndexTestDuplicate {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+
+    pub i: String,
+}
+
+/// Index Named has a basic index with an explicit name
+#[derive(Model, Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[model(collection_name = "indexTest")]
+#[model(index(keys = r#"doc!{"i": 1}"#, options = r#"doc!{"name": "custom-i"}"#))]
+pub struct IndexTestNamed {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+
+    pub i: String,
+}
+
+/// Index Hinted has a basic index, which is used as the model's default hint
+#[derive(Model, Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[model(collection_name = "indexTest", hint = "missing_1")]
+#[model(index(keys = r#"doc!{"i": 1}"#))]
+pub struct IndexTestHinted {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<ObjectId>,
+
+    pub i: String,
+}
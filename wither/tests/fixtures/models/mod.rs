@@ -1,3 +1,5 @@
+pub mod capped_test;
 pub mod index_test;
 
+pub use capped_test::*;
 pub use index_test::*;
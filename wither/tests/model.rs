@@ -1,14 +1,25 @@
-#![cfg(not(feature = "sync"))]
-
 mod fixtures;
 
 use std::collections::HashMap;
-
-use fixtures::{models::*, Fixture, User};
-use futures::stream::StreamExt;
-use wither::bson::doc;
-use wither::mongodb::options::{FindOneAndReplaceOptions, FindOneAndUpdateOptions, ReturnDocument};
-use wither::{prelude::*, IndexModel};
+use std::sync::Mutex;
+
+use chrono::TimeZone;
+
+use fixtures::{
+    models::*, DefaultedUser, Fixture, ReadOnlyUser, SnapshotUser, SparseTrackedUser, TrackedUser, User, UserModelBadMigrations,
+    UserModelExpiredIndexedMigrations, UserModelIndexedMigrations, ValidatedUser, VersionedUser,
+};
+use futures::stream::{StreamExt, TryStream, TryStreamExt};
+use serde::Deserialize;
+use wither::bson::{doc, Document};
+use wither::mongodb::options::{
+    CollectionOptions, CreateCollectionOptions, FindOneAndReplaceOptions, FindOneAndUpdateOptions, FindOneOptions, Hint, ReadPreference,
+    ReturnDocument, SelectionCriteria, ValidationLevel,
+};
+use wither::{
+    prelude::*, CacheProvider, ExplainVerbosity, IndexModel, IntervalMigration, MigrationReport, MigrationStatus, RenameMigration, SaveOutcome,
+    SyncOptions, UpdateBuilder, WriteModel,
+};
 
 //////////////////////////////////////////////////////////////////////////////
 // Model::find ///////////////////////////////////////////////////////////////
@@ -65,11 +76,16 @@ async fn model_find_should_find_instances_of_model_matching_filter() {
     assert_eq!(userdb, user);
 }
 
-//////////////////////////////////////////////////////////////////////////////
-// Model::find_one ///////////////////////////////////////////////////////////
-
 #[tokio::test]
-async fn model_find_one_should_fetch_the_model_instance_matching_given_filter() {
+async fn model_find_should_accept_bare_documents_and_filter_builders() {
+    struct EmailFilter(&'static str);
+
+    impl From<EmailFilter> for Option<Document> {
+        fn from(filter: EmailFilter) -> Self {
+            Some(doc! {"email": filter.0})
+        }
+    }
+
     let fixture = Fixture::new()
         .await
         .with_dropped_database()
@@ -78,24 +94,38 @@ async fn model_find_one_should_fetch_the_model_instance_matching_given_filter()
         .await;
     let db = fixture.get_db();
     let mut user = User { id: None, email: "test@test.com".to_string() };
-
     user.save(&db, None).await.expect("Expected a successful save operation.");
 
-    let doc = doc! {"_id": (user.id)};
-    let user_from_db = User::find_one(&db, Some(doc), None)
+    let by_document: Vec<_> = User::find(&db, doc! {"_id": user.id.unwrap()}, None)
         .await
         .expect("Expected a successful lookup.")
-        .expect("Expected a populated value from backend.");
+        .try_collect()
+        .await
+        .expect("Expected a successful cursor read.");
+    let by_builder: Vec<_> = User::find(&db, EmailFilter("test@test.com"), None)
+        .await
+        .expect("Expected a successful lookup.")
+        .try_collect()
+        .await
+        .expect("Expected a successful cursor read.");
 
-    assert_eq!(&user_from_db.id, &user.id);
-    assert_eq!(&user_from_db.email, &user.email);
+    assert_eq!(by_document, vec![user.clone()]);
+    assert_eq!(by_builder, vec![user]);
 }
 
-//////////////////////////////////////////////////////////////////////////////
-// Model::find_one_and_delete ////////////////////////////////////////////////
+#[tokio::test]
+async fn model_namespace_should_match_collection_namespace() {
+    let fixture = Fixture::new().await;
+    let db = fixture.get_db();
+
+    let namespace = User::namespace(&db);
+
+    assert_eq!(namespace, "witherTestDB.users");
+    assert_eq!(namespace, User::collection(&db).namespace().to_string());
+}
 
 #[tokio::test]
-async fn model_find_one_and_delete_should_delete_the_target_doc() {
+async fn model_find_should_support_cursor_try_next() {
     let fixture = Fixture::new()
         .await
         .with_dropped_database()
@@ -104,23 +134,46 @@ async fn model_find_one_and_delete_should_delete_the_target_doc() {
         .await;
     let db = fixture.get_db();
     let mut user = User { id: None, email: "test@test.com".to_string() };
-    let mut user2 = User { id: None, email: "test2@test.com".to_string() };
-
     user.save(&db, None).await.expect("Expected a successful save operation.");
-    user2.save(&db, None).await.expect("Expected a successful save operation.");
-    let output = User::find_one_and_delete(&db, doc! {"email": "test@test.com"}, None)
-        .await
-        .expect("Expected a operation.")
-        .unwrap();
 
-    assert_eq!(&output.email, &user.email);
+    let mut cursor = User::find(&db, None, None).await.expect("Expected a successful lookup.");
+    let mut users_from_db = vec![];
+    while let Some(user) = cursor.try_next().await.expect("Expected a successful cursor read.") {
+        users_from_db.push(user);
+    }
+
+    assert_eq!(users_from_db, vec![user]);
 }
 
-//////////////////////////////////////////////////////////////////////////////
-// Model::find_one_and_replace ///////////////////////////////////////////////
+#[tokio::test]
+async fn model_tail_should_stream_new_documents_from_capped_collection() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+    let capped = CreateCollectionOptions::builder()
+        .capped(Some(true))
+        .size(Some(4096))
+        .build();
+    db.create_collection(User::COLLECTION_NAME, capped)
+        .await
+        .expect("Expected a successful collection creation.");
+    let mut first = User { id: None, email: "first@test.com".to_string() };
+    first.save(&db, None).await.expect("Expected a successful save operation.");
+
+    let mut cursor = User::tail(&db, None).await.expect("Expected a successful tail.");
+    let existing = cursor.try_next().await.expect("Expected a successful cursor read.");
+    let mut second = User { id: None, email: "second@test.com".to_string() };
+    second
+        .save(&db, None)
+        .await
+        .expect("Expected a successful save operation.");
+    let inserted = cursor.try_next().await.expect("Expected a successful cursor read.");
+
+    assert_eq!(existing, Some(first));
+    assert_eq!(inserted, Some(second));
+}
 
 #[tokio::test]
-async fn model_find_one_and_replace_should_replace_the_target_doc_and_return_new_doc() {
+async fn model_count_by_should_group_counts_by_field() {
     let fixture = Fixture::new()
         .await
         .with_dropped_database()
@@ -128,28 +181,38 @@ async fn model_find_one_and_replace_should_replace_the_target_doc_and_return_new
         .with_synced_models()
         .await;
     let db = fixture.get_db();
-    let mut user = User { id: None, email: "test@test.com".to_string() };
-    let mut user2 = User { id: None, email: "test2@test.com".to_string() };
-    let mut opts = FindOneAndReplaceOptions::builder().build();
-    opts.return_document = Some(ReturnDocument::After);
-
-    user.save(&db, None).await.expect("Expected a successful save operation.");
-    user2.save(&db, None).await.expect("Expected a successful save operation.");
-    let output = User::find_one_and_replace(
-        &db,
-        doc! {"email": "test@test.com"},
-        &User { id: None, email: "test3@test.com".to_owned() },
-        Some(opts),
-    )
-    .await
-    .expect("Expected a operation.")
-    .unwrap();
-
-    assert_eq!(&output.email, "test3@test.com");
+    User::collection(&db)
+        .clone_with_type::<wither::bson::Document>()
+        .insert_many(
+            vec![
+                doc! {"email": "a@test.com", "status": "active"},
+                doc! {"email": "b@test.com", "status": "active"},
+                doc! {"email": "c@test.com", "status": "banned"},
+                doc! {"email": "d@test.com", "status": 1},
+                doc! {"email": "e@test.com"},
+            ],
+            None,
+        )
+        .await
+        .expect("Expected a successful insert operation.");
+
+    let counts = User::count_by(&db, "status", None)
+        .await
+        .expect("Expected a successful count.");
+    let filtered = User::count_by(&db, "status", doc! {"email": {"$ne": "a@test.com"}})
+        .await
+        .expect("Expected a successful count.");
+
+    assert_eq!(counts.len(), 4);
+    assert_eq!(counts["active"], 2);
+    assert_eq!(counts["banned"], 1);
+    assert_eq!(counts["1"], 1);
+    assert_eq!(counts["null"], 1);
+    assert_eq!(filtered["active"], 1);
 }
 
 #[tokio::test]
-async fn model_find_one_and_replace_should_replace_the_target_doc_and_return_old_doc() {
+async fn model_all_should_return_all_instances_in_sort_order() {
     let fixture = Fixture::new()
         .await
         .with_dropped_database()
@@ -157,31 +220,33 @@ async fn model_find_one_and_replace_should_replace_the_target_doc_and_return_old
         .with_synced_models()
         .await;
     let db = fixture.get_db();
-    let mut user = User { id: None, email: "test@test.com".to_string() };
-    let mut user2 = User { id: None, email: "test2@test.com".to_string() };
-    let mut opts = FindOneAndReplaceOptions::builder().build();
-    opts.return_document = Some(ReturnDocument::Before);
+    let mut users = vec![];
+    for email in ["b@test.com", "c@test.com", "a@test.com"].iter() {
+        let mut user = User { id: None, email: email.to_string() };
+        user.save(&db, None).await.expect("Expected a successful save operation.");
+        users.push(user);
+    }
 
-    user.save(&db, None).await.expect("Expected a successful save operation.");
-    user2.save(&db, None).await.expect("Expected a successful save operation.");
-    let output = User::find_one_and_replace(
-        &db,
-        doc! {"email": "test@test.com"},
-        &User { id: None, email: "test3@test.com".to_owned() },
-        Some(opts),
-    )
-    .await
-    .expect("Expected a operation.")
-    .unwrap();
+    let sorted: Vec<_> = User::all(&db, doc! {"email": -1})
+        .await
+        .expect("Expected a successful lookup.")
+        .try_collect()
+        .await
+        .expect("Expected a successful cursor read.");
+    let unsorted: Vec<User> = User::all(&db, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .try_collect()
+        .await
+        .expect("Expected a successful cursor read.");
 
-    assert_eq!(&output.email, "test@test.com");
+    let emails: Vec<_> = sorted.iter().map(|user| user.email.as_str()).collect();
+    assert_eq!(emails, vec!["c@test.com", "b@test.com", "a@test.com"]);
+    assert_eq!(unsorted.len(), 3);
 }
 
-//////////////////////////////////////////////////////////////////////////////
-// Model::find_one_and_update ////////////////////////////////////////////////
-
 #[tokio::test]
-async fn model_find_one_and_update_should_update_target_document_and_return_new() {
+async fn model_find_one_and_upgrade_should_persist_defaulted_fields() {
     let fixture = Fixture::new()
         .await
         .with_dropped_database()
@@ -190,27 +255,28 @@ async fn model_find_one_and_update_should_update_target_document_and_return_new(
         .await;
     let db = fixture.get_db();
     let mut user = User { id: None, email: "test@test.com".to_string() };
-    let mut user2 = User { id: None, email: "test2@test.com".to_string() };
-    let mut opts = FindOneAndUpdateOptions::builder().build();
-    opts.return_document = Some(ReturnDocument::After);
-
     user.save(&db, None).await.expect("Expected a successful save operation.");
-    user2.save(&db, None).await.expect("Expected a successful save operation.");
-    let output = User::find_one_and_update(
-        &db,
-        doc! {"email": "test@test.com"},
-        doc! {"$set": doc!{"email": "test3@test.com"}},
-        Some(opts),
-    )
-    .await
-    .expect("Expected a operation.")
-    .unwrap();
+    let filter = doc! {"_id": user.id.unwrap()};
 
-    assert_eq!(&output.email, "test3@test.com");
+    let upgraded = DefaultedUser::find_one_and_upgrade(&db, filter.clone(), None)
+        .await
+        .expect("Expected a successful lookup.")
+        .expect("Expected a document to be found.");
+    let stored = User::collection(&db)
+        .clone_with_type::<wither::bson::Document>()
+        .find_one(filter.clone(), None)
+        .await
+        .expect("Expected a successful lookup.")
+        .expect("Expected a document to be found.");
+    let (_, defaulted) = DefaultedUser::instance_from_document_defaulted(stored.clone()).expect("Expected a successful decode.");
+
+    assert_eq!(upgraded.logins, 0);
+    assert_eq!(stored.get_i32("logins"), Ok(0));
+    assert!(!defaulted);
 }
 
 #[tokio::test]
-async fn model_find_one_and_update_should_update_target_document_and_return_old() {
+async fn model_find_should_support_cursor_map_ok() {
     let fixture = Fixture::new()
         .await
         .with_dropped_database()
@@ -219,30 +285,21 @@ async fn model_find_one_and_update_should_update_target_document_and_return_old(
         .await;
     let db = fixture.get_db();
     let mut user = User { id: None, email: "test@test.com".to_string() };
-    let mut user2 = User { id: None, email: "test2@test.com".to_string() };
-    let mut opts = FindOneAndUpdateOptions::builder().build();
-    opts.return_document = Some(ReturnDocument::Before);
-
     user.save(&db, None).await.expect("Expected a successful save operation.");
-    user2.save(&db, None).await.expect("Expected a successful save operation.");
-    let output = User::find_one_and_update(
-        &db,
-        doc! {"email": "test@test.com"},
-        doc! {"$set": doc!{"email": "test3@test.com"}},
-        Some(opts),
-    )
-    .await
-    .expect("Expected a operation.")
-    .unwrap();
 
-    assert_eq!(&output.email, "test@test.com");
-}
+    let emails: Vec<_> = User::find(&db, None, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .map_ok(|user| user.email)
+        .collect()
+        .await;
 
-//////////////////////////////////////////////////////////////////////////////
-// Model.save ////////////////////////////////////////////////////////////////
+    assert_eq!(emails.len(), 1);
+    assert_eq!(emails[0].as_ref().unwrap(), "test@test.com");
+}
 
 #[tokio::test]
-async fn model_save_should_save_model_instance_and_add_id() {
+async fn model_find_should_support_cursor_try_collect() {
     let fixture = Fixture::new()
         .await
         .with_dropped_database()
@@ -251,22 +308,20 @@ async fn model_save_should_save_model_instance_and_add_id() {
         .await;
     let db = fixture.get_db();
     let mut user = User { id: None, email: "test@test.com".to_string() };
-
-    let precount = User::collection(&db).count_documents(None, None).await.unwrap();
     user.save(&db, None).await.expect("Expected a successful save operation.");
-    let postcount = User::collection(&db).count_documents(None, None).await.unwrap();
 
-    assert!(user.id != None);
-    assert_eq!(precount, 0);
-    assert_eq!(postcount, 1);
-    assert!(precount != postcount);
-}
+    let users: Vec<User> = User::find(&db, None, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .try_collect()
+        .await
+        .expect("Expected a successful decode.");
 
-//////////////////////////////////////////////////////////////////////////////
-// Model.update //////////////////////////////////////////////////////////////
+    assert_eq!(users, vec![user]);
+}
 
 #[tokio::test]
-async fn model_update_should_perform_expected_updates_against_self() {
+async fn model_find_should_skip_malformed_documents_with_on_error_skip() {
     let fixture = Fixture::new()
         .await
         .with_dropped_database()
@@ -274,22 +329,27 @@ async fn model_update_should_perform_expected_updates_against_self() {
         .with_synced_models()
         .await;
     let db = fixture.get_db();
-    let mut user = User { id: None, email: String::from("test@test.com") };
+    let mut user = User { id: None, email: "test@test.com".to_string() };
     user.save(&db, None).await.expect("Expected a successful save operation.");
-    let update_doc = doc! {"$set": doc!{"email": "new@test.com"}};
-    let mut opts = FindOneAndUpdateOptions::default();
-    opts.return_document = Some(ReturnDocument::After);
+    User::collection(&db)
+        .clone_with_type::<wither::bson::Document>()
+        .insert_one(doc! {"email": 1}, None)
+        .await
+        .expect("Expected a successful insert operation.");
 
-    let user = user
-        .update(&db, None, update_doc, Some(opts))
+    let users: Vec<User> = User::find(&db, None, None)
         .await
-        .expect("Expected a successful update operation.");
+        .expect("Expected a successful lookup.")
+        .on_error_skip()
+        .try_collect()
+        .await
+        .expect("Expected malformed documents to be skipped.");
 
-    assert_eq!(user.email, String::from("new@test.com"));
+    assert_eq!(users, vec![user]);
 }
 
 #[tokio::test]
-async fn model_update_should_return_error_with_invalid_update_document() {
+async fn model_find_should_support_cursor_chunks() {
     let fixture = Fixture::new()
         .await
         .with_dropped_database()
@@ -297,23 +357,25 @@ async fn model_update_should_return_error_with_invalid_update_document() {
         .with_synced_models()
         .await;
     let db = fixture.get_db();
-    let mut user = User { id: None, email: String::from("test@test.com") };
-    user.save(&db, None).await.expect("Expected a successful save operation.");
-    let update_doc = doc! {"invalid_update_key": "should_fail"};
+    for email in ["a@test.com", "b@test.com", "c@test.com"].iter() {
+        let mut user = User { id: None, email: email.to_string() };
+        user.save(&db, None).await.expect("Expected a successful save operation.");
+    }
 
-    let err = user
-        .update(&db, None, update_doc, None)
+    let chunks: Vec<Vec<String>> = User::all(&db, doc! {"email": 1})
         .await
-        .expect_err("Expected an errored update operation.");
+        .expect("Expected a successful lookup.")
+        .chunks(2)
+        .map_ok(|users| users.into_iter().map(|user| user.email).collect())
+        .try_collect()
+        .await
+        .expect("Expected a successful decode.");
 
-    assert_eq!(
-        err.to_string(),
-        "An invalid argument was provided: update document must have first key starting with '$"
-    );
+    assert_eq!(chunks, vec![vec!["a@test.com", "b@test.com"], vec!["c@test.com"]]);
 }
 
 #[tokio::test]
-async fn model_update_should_noop_where_filter_selects_on_nonextant_field() {
+async fn model_find_should_report_the_id_of_malformed_documents() {
     let fixture = Fixture::new()
         .await
         .with_dropped_database()
@@ -321,18 +383,32 @@ async fn model_update_should_noop_where_filter_selects_on_nonextant_field() {
         .with_synced_models()
         .await;
     let db = fixture.get_db();
-    let mut user = User { id: None, email: String::from("test@test.com") };
-    user.save(&db, None).await.expect("Expected a successful save operation.");
-    let filter_doc = Some(doc! {"nonextant_field": doc!{"$exists": true}});
-    let update_doc = doc! {"$set": doc!{"email": "test2@test.com"}};
+    let id = wither::bson::oid::ObjectId::new();
+    User::collection(&db)
+        .clone_with_type::<wither::bson::Document>()
+        .insert_one(doc! {"_id": id, "email": 1}, None)
+        .await
+        .expect("Expected a successful insert operation.");
 
-    let res = user.update(&db, filter_doc, update_doc, None).await;
+    let err = User::find(&db, None, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .try_collect::<Vec<User>>()
+        .await
+        .expect_err("Expected malformed documents to error.");
 
-    assert!(res.is_err());
+    assert!(matches!(err, wither::WitherError::DeserializeWithId { id: Some(err_id), .. } if *err_id == id.into()));
+}
+
+#[test]
+fn model_cursor_should_implement_try_stream() {
+    fn assert_try_stream<S: TryStream<Ok = User, Error = wither::WitherError>>() {}
+
+    assert_try_stream::<wither::ModelCursor<User>>();
 }
 
 #[tokio::test]
-async fn model_update_should_perform_expected_update_with_added_filters() {
+async fn model_find_should_support_cursor_into_typed() {
     let fixture = Fixture::new()
         .await
         .with_dropped_database()
@@ -340,26 +416,27 @@ async fn model_update_should_perform_expected_update_with_added_filters() {
         .with_synced_models()
         .await;
     let db = fixture.get_db();
-    let mut user = User { id: None, email: String::from("test@test.com") };
+    let mut user = User { id: None, email: "test@test.com".to_string() };
     user.save(&db, None).await.expect("Expected a successful save operation.");
-    let filter_doc = Some(doc! {"nonextant_field": doc!{"$exists": false}});
-    let update_doc = doc! {"$set": doc!{"email": "test2@test.com"}};
-    let mut opts = FindOneAndUpdateOptions::default();
-    opts.return_document = Some(ReturnDocument::After);
 
-    let user = user
-        .update(&db, filter_doc, update_doc, Some(opts))
+    #[derive(Deserialize)]
+    struct Email {
+        email: String,
+    }
+    let emails: Vec<Email> = User::find(&db, None, None)
         .await
-        .expect("Expected a successful update operation.");
+        .expect("Expected a successful lookup.")
+        .into_typed::<Email>()
+        .try_collect()
+        .await
+        .expect("Expected successful deserialization.");
 
-    assert_eq!(user.email, String::from("test2@test.com"));
+    assert_eq!(emails.len(), 1);
+    assert_eq!(emails[0].email, "test@test.com");
 }
 
-//////////////////////////////////////////////////////////////////////////////
-// Model.delete //////////////////////////////////////////////////////////////
-
 #[tokio::test]
-async fn model_delete_should_delete_model_instance() {
+async fn model_find_with_collection_options_should_find_all_instances_of_model() {
     let fixture = Fixture::new()
         .await
         .with_dropped_database()
@@ -368,25 +445,25 @@ async fn model_delete_should_delete_model_instance() {
         .await;
     let db = fixture.get_db();
     let mut user = User { id: None, email: "test@test.com".to_string() };
-
-    let presave = User::collection(&db).count_documents(None, None).await.unwrap();
     user.save(&db, None).await.expect("Expected a successful save operation.");
-    let postsave = User::collection(&db).count_documents(None, None).await.unwrap();
-    user.delete(&db).await.unwrap();
-    let postdelete = User::collection(&db).count_documents(None, None).await.unwrap();
+    let coll_opts = CollectionOptions::builder()
+        .selection_criteria(Some(SelectionCriteria::ReadPreference(ReadPreference::PrimaryPreferred {
+            options: Default::default(),
+        })))
+        .build();
 
-    assert_eq!(presave, 0);
-    assert_eq!(postsave, 1);
-    assert_eq!(postdelete, 0);
-    assert!(presave != postsave);
-    assert!(postsave != postdelete);
-}
+    let users_from_db: Vec<_> = User::find_with_collection_options(&db, Some(coll_opts), None, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .collect()
+        .await;
 
-//////////////////////////////////////////////////////////////////////////////
-// Model.delete_many /////////////////////////////////////////////////////////
+    assert_eq!(users_from_db.len(), 1);
+    assert_eq!(users_from_db[0].as_ref().unwrap(), &user);
+}
 
 #[tokio::test]
-async fn model_delete_many_should_delete_all_documents() {
+async fn model_find_with_should_apply_find_options() {
     let fixture = Fixture::new()
         .await
         .with_dropped_database()
@@ -394,26 +471,64 @@ async fn model_delete_many_should_delete_all_documents() {
         .with_synced_models()
         .await;
     let db = fixture.get_db();
-    let mut user = User { id: None, email: "test@test.com".to_string() };
-    let mut user2 = User { id: None, email: "test2@test.com".to_string() };
+    for idx in 0..3 {
+        let mut user = User { id: None, email: format!("test-{}@test.com", idx) };
+        user.save(&db, None).await.expect("Expected a successful save operation.");
+    }
+    let options = wither::mongodb::options::FindOptions::builder()
+        .batch_size(Some(1))
+        .no_cursor_timeout(Some(true))
+        .sort(Some(doc! {"email": -1}))
+        .build();
+
+    let mut cursor = User::find_with(&db, doc! {}, options)
+        .await
+        .expect("Expected a successful find operation.");
+    let mut emails = vec![];
+    while let Some(user) = cursor.try_next().await.expect("Expected a successful decode.") {
+        emails.push(user.email);
+    }
+
+    assert_eq!(emails, vec!["test-2@test.com", "test-1@test.com", "test-0@test.com"]);
+}
 
-    let presave = User::collection(&db).count_documents(None, None).await.unwrap();
-    user.save(&db, None).await.expect("Expected a successful save operation.");
-    user2.save(&db, None).await.expect("Expected a successful save operation.");
-    let postsave = User::collection(&db).count_documents(None, None).await.unwrap();
-    let delete_result = User::delete_many(&db, doc! {}, None).await.unwrap();
-    let postdelete = User::collection(&db).count_documents(None, None).await.unwrap();
+#[tokio::test]
+async fn model_find_should_apply_default_hint_unless_overridden() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+    IndexTestHinted::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
 
-    assert_eq!(presave, 0);
-    assert_eq!(postsave, 2);
-    assert_eq!(postdelete, 0);
-    assert_eq!(delete_result.deleted_count, 2);
-    assert!(presave != postsave);
-    assert!(postsave != postdelete);
+    // The model's default hint names an index which does not exist, so the server rejects it.
+    let err = IndexTestHinted::find_one(&db, None, None)
+        .await
+        .expect_err("Expected the default hint to be applied.");
+    assert!(matches!(*err_kind(&err), wither::mongodb::error::ErrorKind::Command(_)));
+
+    let opts = FindOneOptions::builder().hint(Some(Hint::Name("i_1".to_string()))).build();
+    IndexTestHinted::find_one(&db, None, opts)
+        .await
+        .expect("Expected the caller's hint to take precedence.");
+}
+
+fn err_kind(err: &wither::WitherError) -> &wither::mongodb::error::ErrorKind {
+    match err {
+        wither::WitherError::Mongo(err) => err.kind.as_ref(),
+        other => panic!("Expected a driver error, got {:?}", other),
+    }
 }
 
+//////////////////////////////////////////////////////////////////////////////
+// Model::typed_collection ///////////////////////////////////////////////////
+
 #[tokio::test]
-async fn model_delete_many_should_delete_all_filtered_documents() {
+async fn model_typed_collection_should_reinterpret_documents() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct UserEmail {
+        email: String,
+    }
+
     let fixture = Fixture::new()
         .await
         .with_dropped_database()
@@ -422,576 +537,3003 @@ async fn model_delete_many_should_delete_all_filtered_documents() {
         .await;
     let db = fixture.get_db();
     let mut user = User { id: None, email: "test@test.com".to_string() };
-    let mut user2 = User { id: None, email: "test2@test.com".to_string() };
-
-    let presave = User::collection(&db).count_documents(None, None).await.unwrap();
     user.save(&db, None).await.expect("Expected a successful save operation.");
-    user2.save(&db, None).await.expect("Expected a successful save operation.");
-    let postsave = User::collection(&db).count_documents(None, None).await.unwrap();
-    let delete_result = User::delete_many(&db, doc! { "email": "test@test.com".to_string() }, None)
-        .await
-        .unwrap();
-    let postdelete = User::collection(&db).count_documents(None, None).await.unwrap();
 
-    let mut remaining_users_from_db: Vec<_> = User::find(&db, None, None)
+    let coll = User::typed_collection::<UserEmail>(&db);
+    let email = coll
+        .find_one(doc! {"_id": user.id.unwrap()}, None)
         .await
         .expect("Expected a successful lookup.")
-        .collect()
-        .await;
-    let remaining_user_from_db = remaining_users_from_db.pop().unwrap();
-    assert!(remaining_user_from_db.is_ok());
-
-    let remaining_user_from_db = remaining_user_from_db.unwrap();
+        .expect("Expected a populated value from backend.");
 
-    assert_eq!(presave, 0);
-    assert_eq!(postsave, 2);
-    assert_eq!(postdelete, 1);
-    assert_eq!(delete_result.deleted_count, 1);
-    assert!(presave != postsave);
-    assert!(postsave != postdelete);
-    assert_eq!(user2.email, remaining_user_from_db.email);
+    assert_eq!(coll.name(), User::COLLECTION_NAME);
+    assert_eq!(email, UserEmail { email: "test@test.com".to_string() });
 }
 
 //////////////////////////////////////////////////////////////////////////////
-// Model::sync ///////////////////////////////////////////////////////////////
+// Model::find_projected /////////////////////////////////////////////////////
 
 #[tokio::test]
-async fn model_sync_should_create_expected_indices_on_collection() {
-    let fixture = Fixture::new().await.with_dropped_database().await;
-    let db = fixture.get_db();
+async fn model_find_projected_should_deserialize_projected_fields() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct UserEmail {
+        email: String,
+    }
 
-    // There should be no indexes now
-    let before_indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+    let fixture = Fixture::new()
         .await
-        .expect("error getting current indexes");
-    assert!(before_indexes.is_empty());
-
-    // It should sync the model
-    IndexTestV1::sync(&db)
+        .with_dropped_database()
         .await
-        .expect("Expected a successful sync operation.");
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
 
-    // After the sync there should be the expected indexes
-    let after_indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+    let emails: Vec<_> = User::find_projected::<UserEmail, _, _>(&db, None, doc! {"_id": 0, "email": 1}, None)
         .await
-        .expect("error getting current indexes");
-    let index_model = after_indexes.get("i_1").expect("Should have index: `i_1`");
-    let index_key_value = index_model
-        .keys
-        .get("i")
-        .expect("Should have key `i`")
-        .as_i32()
-        .expect("`i` should be of type Int32");
-
-    assert_eq!(index_key_value, 1);
-
-    let option_name_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("name")
-        .expect("Should have name option")
-        .as_str()
-        .expect("Should be a valid string");
+        .expect("Expected a successful lookup.")
+        .collect()
+        .await;
 
-    assert_eq!(option_name_value, "i_1");
+    assert_eq!(emails.len(), 1);
+    assert_eq!(emails[0].as_ref().unwrap(), &UserEmail { email: "test@test.com".to_string() });
 }
 
-#[tokio::test]
-async fn model_sync_should_not_modify_indexes() {
-    let fixture = Fixture::new().await.with_dropped_database().await;
-    let db = fixture.get_db();
+//////////////////////////////////////////////////////////////////////////////
+// Model::explain_find ///////////////////////////////////////////////////////
 
-    // There should be no indexes now
-    let before_indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+#[tokio::test]
+async fn model_explain_find_should_return_the_query_plan() {
+    let fixture = Fixture::new()
         .await
-        .expect("error getting current indexes");
-    assert!(before_indexes.is_empty());
-
-    IndexTestV1::sync(&db)
+        .with_dropped_database()
         .await
-        .expect("Expected a successful sync operation.");
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
 
-    // After the sync there should be the expected indexes
-    let after_indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+    let explain = User::explain_find(&db, doc! {"email": "test@test.com"}, None, ExplainVerbosity::ExecutionStats)
         .await
-        .expect("error getting current indexes");
-    let index_model = after_indexes.get("i_1").expect("Should have index: `i_1`");
-    let index_key_value = index_model
-        .keys
-        .get("i")
-        .expect("Should have key `i`")
-        .as_i32()
-        .expect("`i` should be of type Int32");
-
-    assert_eq!(index_key_value, 1);
+        .expect("Expected a successful explain operation.");
 
-    let option_name_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("name")
-        .expect("Should have name option")
-        .as_str()
-        .expect("Should be a valid string");
+    let planner = explain
+        .get_document("queryPlanner")
+        .expect("Expected a query planner section.");
+    assert_eq!(planner.get_str("namespace").unwrap(), User::namespace(&db));
+    let stats = explain
+        .get_document("executionStats")
+        .expect("Expected an execution stats section.");
+    assert_eq!(stats.get_i32("nReturned").unwrap(), 1);
+}
 
-    assert_eq!(option_name_value, "i_1");
+//////////////////////////////////////////////////////////////////////////////
+// Model::find_one ///////////////////////////////////////////////////////////
 
-    IndexTestV1::sync(&db)
+#[tokio::test]
+async fn model_find_one_should_fetch_the_model_instance_matching_given_filter() {
+    let fixture = Fixture::new()
         .await
-        .expect("Expected a successful sync operation.");
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
 
-    // After the sync there should be the expected indexes
-    let after_indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+
+    let doc = doc! {"_id": (user.id)};
+    let user_from_db = User::find_one(&db, Some(doc), None)
         .await
-        .expect("error getting current indexes");
-    let index_model = after_indexes.get("i_1").expect("Should have index: `i_1`");
-    let index_key_value = index_model
-        .keys
-        .get("i")
-        .expect("Should have key `i`")
-        .as_i32()
-        .expect("`i` should be of type Int32");
+        .expect("Expected a successful lookup.")
+        .expect("Expected a populated value from backend.");
 
-    assert_eq!(index_key_value, 1);
+    assert_eq!(&user_from_db.id, &user.id);
+    assert_eq!(&user_from_db.email, &user.email);
+}
 
-    let option_name_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("name")
-        .expect("Should have name option")
-        .as_str()
-        .expect("Should be a valid string");
+//////////////////////////////////////////////////////////////////////////////
+// Model::find_by_id_cached //////////////////////////////////////////////////
 
-    assert_eq!(option_name_value, "i_1");
+#[derive(Default)]
+struct MemoryCache(Mutex<HashMap<String, Document>>);
+
+#[wither::async_trait]
+impl CacheProvider for MemoryCache {
+    async fn get(&self, key: &str) -> wither::Result<Option<Document>> {
+        Ok(self.0.lock().unwrap().get(key).cloned())
+    }
+
+    async fn set(&self, key: &str, value: Document) -> wither::Result<()> {
+        self.0.lock().unwrap().insert(key.to_string(), value);
+        Ok(())
+    }
 }
 
 #[tokio::test]
-async fn model_sync_should_modify_indexes_v1_to_v2() {
-    let fixture = Fixture::new().await.with_dropped_database().await;
+async fn model_find_by_id_cached_should_populate_and_read_through_cache() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
     let db = fixture.get_db();
+    let cache = MemoryCache::default();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    let id = user.id.expect("Expected an ID to be set.");
 
-    // There should be no indexes now
-    let before_indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+    let missing = User::find_by_id_cached(&db, &cache, &wither::bson::oid::ObjectId::new())
         .await
-        .expect("error getting current indexes");
-    assert!(before_indexes.is_empty());
-
-    IndexTestV1::sync(&db)
+        .expect("Expected a successful lookup.");
+    let loaded = User::find_by_id_cached(&db, &cache, &id)
         .await
-        .expect("Expected a successful sync operation.");
+        .expect("Expected a successful lookup.");
+    user.delete(&db).await.expect("Expected a successful delete operation.");
+    let cached = User::find_by_id_cached(&db, &cache, &id)
+        .await
+        .expect("Expected a successful lookup.");
 
-    // After the sync there should be the expected indexes
-    let after_indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+    assert_eq!(missing, None);
+    assert_eq!(loaded.as_ref(), Some(&user));
+    assert_eq!(cached, loaded);
+    assert_eq!(cache.0.lock().unwrap().len(), 1);
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model::count //////////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_count_should_count_matching_documents() {
+    let fixture = Fixture::new()
         .await
-        .expect("error getting current indexes");
-    let index_model = after_indexes.get("i_1").expect("Should have index: `i_1`");
-    let index_key_value = index_model
-        .keys
-        .get("i")
-        .expect("Should have key `i`")
-        .as_i32()
-        .expect("`i` should be of type Int32");
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    for email in &["a@test.com", "b@test.com", "c@other.com"] {
+        let mut user = User { id: None, email: email.to_string() };
+        user.save(&db, None).await.expect("Expected a successful save operation.");
+    }
 
-    assert_eq!(index_key_value, 1);
+    let count = User::count(&db, doc! {"email": {"$regex": "@test.com$"}}).await.unwrap();
+    let none = User::count(&db, doc! {"email": "nope@test.com"}).await.unwrap();
 
-    let option_name_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("name")
-        .expect("Should have name option")
-        .as_str()
-        .expect("Should be a valid string");
+    assert_eq!(count, 2);
+    assert_eq!(none, 0);
+}
 
-    assert_eq!(option_name_value, "i_1");
+//////////////////////////////////////////////////////////////////////////////
+// Model::exists /////////////////////////////////////////////////////////////
 
-    IndexTestV2::sync(&db)
+#[tokio::test]
+async fn model_exists_should_report_whether_any_document_matches() {
+    let fixture = Fixture::new()
         .await
-        .expect("Expected a successful sync operation.");
-
-    // After the sync there should be the expected indexes
-    let after_indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+        .with_dropped_database()
         .await
-        .expect("error getting current indexes");
-    let index_model = after_indexes.get("i_-1").expect("Should have index: `i_-1`");
-    let index_key_value = index_model
-        .keys
-        .get("i")
-        .expect("Should have key `i`")
-        .as_i32()
-        .expect("`i` should be of type Int32");
-
-    assert_eq!(index_key_value, -1);
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
 
-    let option_name_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("name")
-        .expect("Should have name option")
-        .as_str()
-        .expect("Should be a valid string");
+    let found = User::exists(&db, doc! {"email": "test@test.com"}).await.unwrap();
+    let not_found = User::exists(&db, doc! {"email": "nope@test.com"}).await.unwrap();
 
-    assert_eq!(option_name_value, "i_-1");
+    assert!(found);
+    assert!(!not_found);
 }
 
+//////////////////////////////////////////////////////////////////////////////
+// Model::distinct ///////////////////////////////////////////////////////////
+
 #[tokio::test]
-async fn model_sync_should_modify_indexes_v2_to_v3() {
-    let fixture = Fixture::new().await.with_dropped_database().await;
+async fn model_distinct_should_return_distinct_values_of_field() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
     let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    let mut user2 = User { id: None, email: "test2@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    user2.save(&db, None).await.expect("Expected a successful save operation.");
 
-    // There should be no indexes now
-    let before_indexes: HashMap<String, IndexModel> = IndexTestV2::get_current_indexes(&db)
+    let mut emails: Vec<String> = User::distinct(&db, "email", None, None)
         .await
-        .expect("error getting current indexes");
-    assert!(before_indexes.is_empty());
+        .expect("Expected a successful distinct operation.");
+    emails.sort();
 
-    IndexTestV2::sync(&db)
-        .await
-        .expect("Expected a successful sync operation.");
+    assert_eq!(emails, vec!["test2@test.com".to_string(), "test@test.com".to_string()]);
+}
 
-    // After the sync there should be the expected indexes
+//////////////////////////////////////////////////////////////////////////////
+// Model::aggregate //////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_aggregate_should_deserialize_pipeline_output() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct EmailCount {
+        #[serde(rename = "_id")]
+        email: String,
+        count: i32,
+    }
+
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+
+    let pipeline = vec![doc! {"$group": {"_id": "$email", "count": {"$sum": 1}}}];
+    let counts: Vec<_> = User::aggregate::<EmailCount, _>(&db, pipeline, None)
+        .await
+        .expect("Expected a successful aggregation.")
+        .collect()
+        .await;
+
+    assert_eq!(counts.len(), 1);
+    assert_eq!(counts[0].as_ref().unwrap(), &EmailCount { email: "test@test.com".to_string(), count: 1 });
+}
+
+#[tokio::test]
+async fn model_run_pipeline_should_execute_out_stage() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+
+    let pipeline = vec![doc! {"$project": {"email": 1}}, doc! {"$out": "users_out"}];
+    User::run_pipeline(&db, pipeline, None)
+        .await
+        .expect("Expected a successful pipeline execution.");
+    let copied = db
+        .collection::<Document>("users_out")
+        .find_one(doc! {"_id": user.id.unwrap()}, None)
+        .await
+        .expect("Expected a successful lookup.");
+
+    assert_eq!(copied, Some(doc! {"_id": user.id.unwrap(), "email": "test@test.com"}));
+}
+
+#[tokio::test]
+async fn model_aggregate_one_should_deserialize_first_pipeline_output() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Total {
+        count: i32,
+    }
+
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    for email in &["a@test.com", "b@test.com"] {
+        let mut user = User { id: None, email: email.to_string() };
+        user.save(&db, None).await.expect("Expected a successful save operation.");
+    }
+
+    let pipeline = vec![doc! {"$group": {"_id": null, "count": {"$sum": 1}}}];
+    let total = User::aggregate_one::<Total, _>(&db, pipeline, None)
+        .await
+        .expect("Expected a successful aggregation.");
+    let pipeline = vec![doc! {"$match": {"email": "nope@test.com"}}];
+    let none = User::aggregate_one::<Total, _>(&db, pipeline, None)
+        .await
+        .expect("Expected a successful aggregation.");
+
+    assert_eq!(total, Some(Total { count: 2 }));
+    assert_eq!(none, None);
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model::find_one_and_delete ////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_find_one_and_delete_should_delete_the_target_doc() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    let mut user2 = User { id: None, email: "test2@test.com".to_string() };
+
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    user2.save(&db, None).await.expect("Expected a successful save operation.");
+    let output = User::find_one_and_delete(&db, doc! {"email": "test@test.com"}, None)
+        .await
+        .expect("Expected a operation.")
+        .unwrap();
+
+    assert_eq!(&output.email, &user.email);
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model::find_and_delete_many ///////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_find_and_delete_many_should_delete_and_return_matching_docs() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    for email in &["a@queue.com", "b@queue.com", "c@other.com"] {
+        let mut user = User { id: None, email: email.to_string() };
+        user.save(&db, None).await.expect("Expected a successful save operation.");
+    }
+
+    let mut drained = User::find_and_delete_many(&db, doc! {"email": {"$regex": "@queue\\.com$"}})
+        .await
+        .expect("Expected a successful operation.");
+    drained.sort_by(|a, b| a.email.cmp(&b.email));
+    let remaining = User::collection(&db).count_documents(None, None).await.unwrap();
+
+    assert_eq!(
+        drained.iter().map(|user| user.email.as_str()).collect::<Vec<_>>(),
+        vec!["a@queue.com", "b@queue.com"]
+    );
+    assert_eq!(remaining, 1);
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model::find_one_and_replace ///////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_find_one_and_replace_should_replace_the_target_doc_and_return_new_doc() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    let mut user2 = User { id: None, email: "test2@test.com".to_string() };
+    let mut opts = FindOneAndReplaceOptions::builder().build();
+    opts.return_document = Some(ReturnDocument::After);
+
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    user2.save(&db, None).await.expect("Expected a successful save operation.");
+    let output = User::find_one_and_replace(
+        &db,
+        doc! {"email": "test@test.com"},
+        &User { id: None, email: "test3@test.com".to_owned() },
+        Some(opts),
+    )
+    .await
+    .expect("Expected a operation.")
+    .unwrap();
+
+    assert_eq!(&output.email, "test3@test.com");
+}
+
+#[tokio::test]
+async fn model_find_one_and_replace_should_replace_the_target_doc_and_return_old_doc() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    let mut user2 = User { id: None, email: "test2@test.com".to_string() };
+    let mut opts = FindOneAndReplaceOptions::builder().build();
+    opts.return_document = Some(ReturnDocument::Before);
+
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    user2.save(&db, None).await.expect("Expected a successful save operation.");
+    let output = User::find_one_and_replace(
+        &db,
+        doc! {"email": "test@test.com"},
+        &User { id: None, email: "test3@test.com".to_owned() },
+        Some(opts),
+    )
+    .await
+    .expect("Expected a operation.")
+    .unwrap();
+
+    assert_eq!(&output.email, "test@test.com");
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model::find_one_and_update ////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_find_one_and_update_should_update_target_document_and_return_new() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    let mut user2 = User { id: None, email: "test2@test.com".to_string() };
+    let mut opts = FindOneAndUpdateOptions::builder().build();
+    opts.return_document = Some(ReturnDocument::After);
+
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    user2.save(&db, None).await.expect("Expected a successful save operation.");
+    let output = User::find_one_and_update(
+        &db,
+        doc! {"email": "test@test.com"},
+        doc! {"$set": doc!{"email": "test3@test.com"}},
+        Some(opts),
+    )
+    .await
+    .expect("Expected a operation.")
+    .unwrap();
+
+    assert_eq!(&output.email, "test3@test.com");
+}
+
+#[tokio::test]
+async fn model_update_by_id_should_update_target_document_and_return_new() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    let mut user2 = User { id: None, email: "test2@test.com".to_string() };
+    let opts = FindOneAndUpdateOptions::builder()
+        .return_document(Some(ReturnDocument::After))
+        .build();
+
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    user2.save(&db, None).await.expect("Expected a successful save operation.");
+    let output = User::update_by_id(&db, &user.id.unwrap(), doc! {"$set": doc!{"email": "test3@test.com"}}, opts)
+        .await
+        .expect("Expected a successful operation.")
+        .expect("Expected a document to be found.");
+    let missing = User::update_by_id(
+        &db,
+        &wither::bson::oid::ObjectId::new(),
+        doc! {"$set": doc!{"email": "test4@test.com"}},
+        None,
+    )
+    .await
+    .expect("Expected a successful operation.");
+
+    assert_eq!(output.id, user.id);
+    assert_eq!(&output.email, "test3@test.com");
+    assert!(missing.is_none());
+}
+
+#[tokio::test]
+async fn model_find_one_and_update_should_update_target_document_and_return_old() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    let mut user2 = User { id: None, email: "test2@test.com".to_string() };
+    let mut opts = FindOneAndUpdateOptions::builder().build();
+    opts.return_document = Some(ReturnDocument::Before);
+
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    user2.save(&db, None).await.expect("Expected a successful save operation.");
+    let output = User::find_one_and_update(
+        &db,
+        doc! {"email": "test@test.com"},
+        doc! {"$set": doc!{"email": "test3@test.com"}},
+        Some(opts),
+    )
+    .await
+    .expect("Expected a operation.")
+    .unwrap();
+
+    assert_eq!(&output.email, "test@test.com");
+}
+
+#[tokio::test]
+async fn model_find_one_and_update_should_accept_update_builder() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    let opts = FindOneAndUpdateOptions::builder()
+        .return_document(Some(ReturnDocument::After))
+        .build();
+
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    let output = User::find_one_and_update(
+        &db,
+        doc! {"email": "test@test.com"},
+        UpdateBuilder::new().set("email", "test3@test.com"),
+        Some(opts),
+    )
+    .await
+    .expect("Expected a operation.")
+    .unwrap();
+
+    assert_eq!(&output.email, "test3@test.com");
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model::bulk_write /////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_bulk_write_should_execute_mixed_write_models() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    user.email = "updated@test.com".to_string();
+    let new_user = User { id: None, email: "test2@test.com".to_string() };
+
+    let ops = vec![
+        new_user.insert_one_model().unwrap(),
+        user.replace_one_model(false).unwrap(),
+        WriteModel::DeleteOne { filter: doc! {"email": "test2@test.com"} },
+    ];
+    let res = User::bulk_write(&db, ops, None)
+        .await
+        .expect("Expected a successful bulk write.");
+    let user_from_db = User::find_one(&db, doc! {"_id": user.id.unwrap()}, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .expect("Expected a populated value from backend.");
+
+    assert_eq!(res.inserted_count, 1);
+    assert_eq!(res.matched_count, 1);
+    assert_eq!(res.modified_count, 1);
+    assert_eq!(res.deleted_count, 1);
+    assert_eq!(user_from_db, user);
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model::upsert_many ////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_upsert_many_should_insert_and_replace_by_filter() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+
+    let items = vec![
+        (doc! {"email": "test@test.com"}, User { id: None, email: "test@test.com".to_string() }),
+        (doc! {"email": "test2@test.com"}, User { id: None, email: "test2@test.com".to_string() }),
+    ];
+    User::upsert_many(&db, items.clone())
+        .await
+        .expect("Expected a successful upsert operation.");
+    // Upserting the same items again should be a no-op.
+    User::upsert_many(&db, items)
+        .await
+        .expect("Expected a successful upsert operation.");
+
+    let count = User::collection(&db).count_documents(None, None).await.unwrap();
+    let existing = User::find_one(&db, doc! {"email": "test@test.com"}, None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(count, 2);
+    assert_eq!(existing.id, user.id);
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model.save ////////////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_save_should_save_model_instance_and_add_id() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+
+    let precount = User::collection(&db).count_documents(None, None).await.unwrap();
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    let postcount = User::collection(&db).count_documents(None, None).await.unwrap();
+
+    assert!(user.id.is_some());
+    assert_eq!(precount, 0);
+    assert_eq!(postcount, 1);
+    assert!(precount != postcount);
+}
+
+#[tokio::test]
+async fn model_save_outcome_should_report_inserts_and_replacements() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+
+    let inserted = user
+        .save_outcome(&db, None)
+        .await
+        .expect("Expected a successful save operation.");
+    assert_eq!(inserted, SaveOutcome::Inserted { id: user.id.unwrap() });
+
+    user.email = "new@test.com".to_string();
+    let replaced = user
+        .save_outcome(&db, None)
+        .await
+        .expect("Expected a successful save operation.");
+    assert_eq!(replaced, SaveOutcome::Replaced { id: user.id.unwrap() });
+
+    let mut other = User { id: None, email: "other@test.com".to_string() };
+    let upserted = other
+        .save_outcome(&db, Some(doc! {"email": "other@test.com"}))
+        .await
+        .expect("Expected a successful save operation.");
+    assert!(upserted.is_inserted());
+    assert_eq!(Some(upserted.id()), other.id);
+}
+
+#[tokio::test]
+async fn model_save_validated_should_save_valid_instance() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+
+    user.save_validated(&db, None)
+        .await
+        .expect("Expected a successful save operation.");
+
+    assert!(user.id.is_some());
+}
+
+#[tokio::test]
+async fn model_save_validated_should_reject_invalid_instance() {
+    let fixture = Fixture::new().await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: String::new() };
+
+    let err = user
+        .save_validated(&db, None)
+        .await
+        .expect_err("Expected an errored save operation.");
+
+    assert!(matches!(err, wither::WitherError::Validation(msg) if msg == "email must not be empty"));
+    assert!(user.id.is_none());
+}
+
+#[tokio::test]
+async fn model_save_with_retry_should_save_instance() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+
+    user.save_with_retry(&db, None, 3)
+        .await
+        .expect("Expected a successful save operation.");
+
+    assert!(user.id.is_some());
+}
+
+#[tokio::test]
+async fn model_save_with_retry_should_return_last_error_once_retries_are_exhausted() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    let mut duplicate = User { id: None, email: "test@test.com".to_string() };
+
+    let err = duplicate
+        .save_with_retry(&db, None, 2)
+        .await
+        .expect_err("Expected a duplicate key error.");
+
+    assert!(err.is_duplicate_key());
+}
+
+#[tokio::test]
+async fn model_read_only_should_reject_writes() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    let mut read_only = ReadOnlyUser::find_one(&db, doc! {"_id": user.id.unwrap()}, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .expect("Expected a document to be found.");
+    let expected = wither::WitherError::ReadOnlyModel("users".to_string());
+
+    read_only.email = "updated@test.com".to_string();
+    let save_err = read_only.save(&db, None).await.expect_err("Expected an errored save.");
+    let delete_err = read_only.delete(&db).await.expect_err("Expected an errored delete.");
+    let delete_many_err = ReadOnlyUser::delete_many(&db, doc! {}, None)
+        .await
+        .expect_err("Expected an errored delete.");
+    let ensure_index_err = ReadOnlyUser::ensure_index(&db, IndexModel::new(doc! {"report": 1}, None))
+        .await
+        .expect_err("Expected an errored ensure index operation.");
+    let drop_indexes_err = ReadOnlyUser::drop_indexes(&db)
+        .await
+        .expect_err("Expected an errored drop.");
+    let drop_collection_err = ReadOnlyUser::drop_collection(&db)
+        .await
+        .expect_err("Expected an errored drop.");
+    let count = User::count(&db, doc! {"email": "test@test.com"}).await.unwrap();
+    let indexes = User::get_current_indexes(&db).await.unwrap();
+
+    assert_eq!(save_err, expected);
+    assert_eq!(delete_err, expected);
+    assert_eq!(delete_many_err, expected);
+    assert_eq!(ensure_index_err, expected);
+    assert_eq!(drop_indexes_err, expected);
+    assert_eq!(drop_collection_err, expected);
+    assert_eq!(count, 1);
+    assert!(indexes.contains_key("unique-email"));
+    assert!(!indexes.contains_key("report_1"));
+}
+
+#[tokio::test]
+async fn model_save_versioned_should_insert_then_increment_version() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+    let mut user = VersionedUser {
+        id: None,
+        email: "test@test.com".to_string(),
+        version: 0,
+        logins: 0,
+    };
+
+    user.save_versioned(&db).await.expect("Expected a successful insert.");
+    user.email = "updated@test.com".to_string();
+    user.save_versioned(&db).await.expect("Expected a successful replace.");
+    let stored = VersionedUser::find_one(&db, doc! {"_id": user.id.unwrap()}, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .expect("Expected a document to be found.");
+
+    assert_eq!(user.version, 2);
+    assert_eq!(stored, user);
+}
+
+#[tokio::test]
+async fn model_save_versioned_should_reject_stale_instance() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+    let mut user = VersionedUser {
+        id: None,
+        email: "test@test.com".to_string(),
+        version: 0,
+        logins: 0,
+    };
+    user.save_versioned(&db).await.expect("Expected a successful insert.");
+    let mut stale = user.clone();
+    user.save_versioned(&db).await.expect("Expected a successful replace.");
+
+    stale.email = "stale@test.com".to_string();
+    let err = stale.save_versioned(&db).await.expect_err("Expected a version conflict.");
+
+    assert!(matches!(err, wither::WitherError::VersionConflict(coll) if coll == "versioned_users"));
+    assert_eq!(stale.version, 1);
+    assert_eq!(stale.email, "stale@test.com");
+}
+
+#[tokio::test]
+async fn model_save_versioned_should_not_overwrite_no_overwrite_fields() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+    let mut user = VersionedUser {
+        id: None,
+        email: "test@test.com".to_string(),
+        version: 0,
+        logins: 1,
+    };
+    user.save_versioned(&db).await.expect("Expected a successful insert.");
+    VersionedUser::collection(&db)
+        .update_one(doc! {"_id": user.id.unwrap()}, doc! {"$inc": {"logins": 4}}, None)
+        .await
+        .expect("Expected a successful update operation.");
+
+    user.email = "new@test.com".to_string();
+    user.save_versioned(&db).await.expect("Expected a successful update.");
+    let stored = VersionedUser::find_one(&db, doc! {"_id": user.id.unwrap()}, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .expect("Expected a document to be found.");
+
+    assert_eq!(stored.email, "new@test.com");
+    assert_eq!(stored.version, 2);
+    assert_eq!(stored.logins, 5);
+}
+
+#[tokio::test]
+async fn model_save_versioned_should_require_version_field() {
+    let fixture = Fixture::new().await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+
+    let err = user
+        .save_versioned(&db)
+        .await
+        .expect_err("Expected an errored save operation.");
+
+    assert!(matches!(err, wither::WitherError::VersionFieldRequired));
+    assert!(user.id.is_none());
+}
+
+#[tokio::test]
+async fn model_save_with_session_should_not_persist_when_transaction_is_aborted() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut session = fixture
+        .get_client()
+        .start_session(None)
+        .await
+        .expect("Expected a session.");
+    let mut user = User { id: None, email: "account@test.com".to_string() };
+    let mut profile = User { id: None, email: "profile@test.com".to_string() };
+
+    session
+        .start_transaction(None)
+        .await
+        .expect("Expected a transaction to start.");
+    user.save_with_session(&db, &mut session, None)
+        .await
+        .expect("Expected a successful save operation.");
+    profile
+        .save_with_session(&db, &mut session, None)
+        .await
+        .expect("Expected a successful save operation.");
+    session
+        .abort_transaction()
+        .await
+        .expect("Expected the transaction to abort.");
+
+    assert!(user.id.is_some());
+    assert!(profile.id.is_some());
+    assert_eq!(User::collection(&db).count_documents(None, None).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn model_save_with_session_should_persist_when_transaction_is_committed() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut session = fixture
+        .get_client()
+        .start_session(None)
+        .await
+        .expect("Expected a session.");
+    let mut user = User { id: None, email: "account@test.com".to_string() };
+
+    session
+        .start_transaction(None)
+        .await
+        .expect("Expected a transaction to start.");
+    user.save_with_session(&db, &mut session, None)
+        .await
+        .expect("Expected a successful save operation.");
+    session
+        .commit_transaction()
+        .await
+        .expect("Expected the transaction to commit.");
+
+    let user_from_db = User::find_one(&db, doc! {"_id": user.id.unwrap()}, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .expect("Expected a populated value from backend.");
+    assert_eq!(user_from_db, user);
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// with_transaction //////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn with_transaction_should_commit_when_callback_succeeds() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+
+    let user = wither::with_transaction(fixture.get_client(), |session| {
+        let db = db.clone();
+        Box::pin(async move {
+            let mut user = User { id: None, email: "account@test.com".to_string() };
+            user.save_with_session(&db, session, None).await?;
+            Ok(user)
+        })
+    })
+    .await
+    .expect("Expected the transaction to commit.");
+
+    let user_from_db = User::find_one(&db, doc! {"_id": user.id.unwrap()}, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .expect("Expected a populated value from backend.");
+    assert_eq!(user_from_db, user);
+}
+
+#[tokio::test]
+async fn with_transaction_should_abort_when_callback_fails() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+
+    let err = wither::with_transaction(fixture.get_client(), |session| {
+        let db = db.clone();
+        Box::pin(async move {
+            let mut user = User { id: None, email: "account@test.com".to_string() };
+            user.save_with_session(&db, session, None).await?;
+            Err::<User, _>(wither::WitherError::Validation("rejected".into()))
+        })
+    })
+    .await
+    .expect_err("Expected the transaction to fail.");
+
+    assert_eq!(err, wither::WitherError::Validation("rejected".into()));
+    assert_eq!(User::collection(&db).count_documents(None, None).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn model_save_with_id_should_insert_using_given_id() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut bytes = wither::bson::oid::ObjectId::new().bytes();
+    bytes[..4].copy_from_slice(&1_000_000_000u32.to_be_bytes());
+    let id = wither::bson::oid::ObjectId::from_bytes(bytes);
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+
+    user.save_with_id(&db, id)
+        .await
+        .expect("Expected a successful save operation.");
+    let err = user
+        .save_with_id(&db, id)
+        .await
+        .expect_err("Expected an errored save operation.");
+    let user_from_db = User::find_one(&db, doc! {"_id": id}, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .expect("Expected a populated value from backend.");
+
+    assert_eq!(user.id, Some(id));
+    assert_eq!(user_from_db, user);
+    assert_eq!(id.timestamp().timestamp_millis(), 1_000_000_000_000);
+    assert_eq!(err, wither::WitherError::ModelIdAlreadySet);
+}
+
+#[tokio::test]
+async fn model_save_with_id_should_not_overwrite_existing_documents() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let id = wither::bson::oid::ObjectId::new();
+    let mut first = User { id: None, email: "first@test.com".to_string() };
+    let mut second = User { id: None, email: "second@test.com".to_string() };
+
+    first
+        .save_with_id(&db, id)
+        .await
+        .expect("Expected a successful save operation.");
+    let err = second
+        .save_with_id(&db, id)
+        .await
+        .expect_err("Expected an errored save operation.");
+    let user_from_db = User::find_one(&db, doc! {"_id": id}, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .expect("Expected a populated value from backend.");
+
+    assert!(err.is_duplicate_key());
+    assert_eq!(second.id, None);
+    assert_eq!(user_from_db, first);
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model.save_new ////////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_save_new_should_insert_model_instance_and_add_id() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+
+    user.save_new(&db).await.expect("Expected a successful save operation.");
+    let user_from_db = User::find_one(&db, doc! {"_id": user.id.unwrap()}, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .expect("Expected a populated value from backend.");
+
+    assert_eq!(user_from_db, user);
+}
+
+#[tokio::test]
+async fn model_save_new_should_error_when_instance_has_id() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+
+    let err = user.save_new(&db).await.expect_err("Expected an errored save operation.");
+
+    assert!(matches!(err, wither::WitherError::ModelIdAlreadySet));
+}
+
+#[tokio::test]
+async fn model_save_should_not_overwrite_no_overwrite_fields() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = TrackedUser {
+        id: None,
+        email: "test@test.com".to_string(),
+        logins: 1,
+    };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    let inserted = TrackedUser::find_one(&db, doc! {"_id": user.id.unwrap()}, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .expect("Expected a populated value from backend.");
+    TrackedUser::collection(&db)
+        .update_one(doc! {"_id": user.id.unwrap()}, doc! {"$inc": {"logins": 4}}, None)
+        .await
+        .expect("Expected a successful update operation.");
+
+    user.email = "new@test.com".to_string();
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    let saved = TrackedUser::find_one(&db, doc! {"_id": user.id.unwrap()}, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .expect("Expected a populated value from backend.");
+
+    assert_eq!(inserted.logins, 1);
+    assert_eq!(saved.email, "new@test.com");
+    assert_eq!(saved.logins, 5);
+}
+
+#[tokio::test]
+async fn model_save_should_handle_no_overwrite_instances_serialized_as_only_an_id() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+    let mut user = SparseTrackedUser { id: None, logins: None };
+
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    TrackedUser::collection(&db)
+        .update_one(doc! {"_id": user.id.unwrap()}, doc! {"$set": {"logins": 3}}, None)
+        .await
+        .expect("Expected a successful update operation.");
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    let outcome = user
+        .save_outcome(&db, None)
+        .await
+        .expect("Expected a successful save operation.");
+    let saved = SparseTrackedUser::find_one(&db, doc! {"_id": user.id.unwrap()}, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .expect("Expected a populated value from backend.");
+
+    assert_eq!(outcome, SaveOutcome::Replaced { id: user.id.unwrap() });
+    assert_eq!(saved.logins, Some(3));
+}
+
+#[tokio::test]
+async fn model_save_if_dirty_should_skip_unchanged_instances() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = SnapshotUser {
+        id: None,
+        email: "test@test.com".to_string(),
+        snapshot: None,
+    };
+
+    let saved_new = user
+        .save_if_dirty(&db)
+        .await
+        .expect("Expected a successful save operation.");
+    let saved_unchanged = user
+        .save_if_dirty(&db)
+        .await
+        .expect("Expected a successful save operation.");
+    user.email = "new@test.com".to_string();
+    let saved_changed = user
+        .save_if_dirty(&db)
+        .await
+        .expect("Expected a successful save operation.");
+    let user_from_db = SnapshotUser::find_one(&db, doc! {"_id": user.id.unwrap()}, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .expect("Expected a populated value from backend.");
+
+    assert!(saved_new);
+    assert!(!saved_unchanged);
+    assert!(saved_changed);
+    assert_eq!(user_from_db.email, "new@test.com");
+    assert_eq!(user_from_db.snapshot, None);
+}
+
+#[tokio::test]
+async fn model_insert_one_should_return_instance_with_id() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+
+    let user = User::insert_one(User { id: None, email: "test@test.com".to_string() }, &db)
+        .await
+        .expect("Expected a successful insert operation.");
+    let user_from_db = User::find_one(&db, doc! {"_id": user.id.expect("Expected an ID to be set.")}, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .expect("Expected a populated value from backend.");
+    let err = User::insert_one(user.clone(), &db)
+        .await
+        .expect_err("Expected an errored insert operation.");
+
+    assert_eq!(user_from_db, user);
+    assert_eq!(err, wither::WitherError::ModelIdAlreadySet);
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model.replace /////////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_replace_should_replace_existing_document() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    user.email = "new@test.com".to_string();
+
+    user.replace(&db).await.expect("Expected a successful replace operation.");
+    let user_from_db = User::find_one(&db, doc! {"_id": user.id.unwrap()}, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .expect("Expected a populated value from backend.");
+
+    assert_eq!(user_from_db, user);
+}
+
+#[tokio::test]
+async fn model_replace_should_error_when_document_does_not_exist() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let user = User {
+        id: Some(wither::bson::oid::ObjectId::new()),
+        email: "test@test.com".to_string(),
+    };
+
+    let err = user
+        .replace(&db)
+        .await
+        .expect_err("Expected an errored replace operation.");
+    let count = User::count(&db, doc! {}).await.expect("Expected a successful count.");
+
+    assert_eq!(err, wither::WitherError::DocumentNotFound("users".to_string()));
+    assert_eq!(count, 0);
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model.reload //////////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_reload_should_refresh_instance_from_database() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    User::collection(&db)
+        .update_one(doc! {"_id": user.id.unwrap()}, doc! {"$set": {"email": "new@test.com"}}, None)
+        .await
+        .expect("Expected a successful update operation.");
+
+    user.reload(&db).await.expect("Expected a successful reload operation.");
+
+    assert_eq!(user.email, String::from("new@test.com"));
+}
+
+#[tokio::test]
+async fn model_reload_should_error_when_instance_has_no_id() {
+    let fixture = Fixture::new().await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+
+    let err = user.reload(&db).await.expect_err("Expected an errored reload operation.");
+
+    assert!(matches!(err, wither::WitherError::ModelIdRequiredForOperation));
+}
+
+#[tokio::test]
+async fn model_reload_should_error_when_document_no_longer_exists() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    user.delete(&db).await.expect("Expected a successful delete operation.");
+
+    let err = user.reload(&db).await.expect_err("Expected an errored reload operation.");
+
+    assert!(matches!(err, wither::WitherError::DocumentNotFound(name) if name == User::COLLECTION_NAME));
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model.find_one_or_insert //////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_find_one_or_insert_should_insert_when_absent() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+
+    let res = user
+        .find_one_or_insert(&db, doc! {"email": "test@test.com"})
+        .await
+        .expect("Expected a successful find_one_or_insert operation.");
+
+    assert!(user.id.is_some());
+    assert_eq!(res, user);
+    assert_eq!(User::collection(&db).count_documents(None, None).await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn model_find_one_or_insert_should_return_existing_when_present() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut existing = User { id: None, email: "test@test.com".to_string() };
+    existing
+        .save(&db, None)
+        .await
+        .expect("Expected a successful save operation.");
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+
+    let res = user
+        .find_one_or_insert(&db, doc! {"email": "test@test.com"})
+        .await
+        .expect("Expected a successful find_one_or_insert operation.");
+
+    assert!(user.id.is_none());
+    assert_eq!(res, existing);
+    assert_eq!(User::collection(&db).count_documents(None, None).await.unwrap(), 1);
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model.update //////////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_update_should_perform_expected_updates_against_self() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: String::from("test@test.com") };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    let update_doc = doc! {"$set": doc!{"email": "new@test.com"}};
+    let mut opts = FindOneAndUpdateOptions::default();
+    opts.return_document = Some(ReturnDocument::After);
+
+    let user = user
+        .update(&db, None, update_doc, Some(opts))
+        .await
+        .expect("Expected a successful update operation.");
+
+    assert_eq!(user.email, String::from("new@test.com"));
+}
+
+#[tokio::test]
+async fn model_update_should_return_error_with_invalid_update_document() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: String::from("test@test.com") };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    let update_doc = doc! {"invalid_update_key": "should_fail"};
+
+    let err = user
+        .update(&db, None, update_doc, None)
+        .await
+        .expect_err("Expected an errored update operation.");
+
+    assert_eq!(
+        err.to_string(),
+        "An invalid argument was provided: update document must have first key starting with '$"
+    );
+}
+
+#[tokio::test]
+async fn model_update_should_noop_where_filter_selects_on_nonextant_field() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: String::from("test@test.com") };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    let filter_doc = Some(doc! {"nonextant_field": doc!{"$exists": true}});
+    let update_doc = doc! {"$set": doc!{"email": "test2@test.com"}};
+
+    let res = user.update(&db, filter_doc, update_doc, None).await;
+
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn model_update_should_return_document_not_found_when_filter_matches_nothing() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: String::from("test@test.com") };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    let filter_doc = Some(doc! {"nonextant_field": doc!{"$exists": true}});
+    let update_doc = doc! {"$set": doc!{"email": "test2@test.com"}};
+
+    let err = user
+        .update(&db, filter_doc, update_doc, None)
+        .await
+        .expect_err("Expected an errored update operation.");
+
+    assert!(matches!(err, wither::WitherError::DocumentNotFound(name) if name == User::COLLECTION_NAME));
+}
+
+#[tokio::test]
+async fn model_update_should_perform_expected_update_with_added_filters() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: String::from("test@test.com") };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    let filter_doc = Some(doc! {"nonextant_field": doc!{"$exists": false}});
+    let update_doc = doc! {"$set": doc!{"email": "test2@test.com"}};
+    let mut opts = FindOneAndUpdateOptions::default();
+    opts.return_document = Some(ReturnDocument::After);
+
+    let user = user
+        .update(&db, filter_doc, update_doc, Some(opts))
+        .await
+        .expect("Expected a successful update operation.");
+
+    assert_eq!(user.email, String::from("test2@test.com"));
+}
+
+#[tokio::test]
+async fn model_update_self_should_persist_only_changed_fields() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: String::from("test@test.com") };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    // Simulate a concurrent write of a field which this instance is unaware of.
+    User::collection(&db)
+        .update_one(doc! {"_id": user.id}, doc! {"$set": {"login_count": 5}}, None)
+        .await
+        .expect("Expected a successful update operation.");
+
+    user.update_self(&db, |user| user.email = String::from("test2@test.com"))
+        .await
+        .expect("Expected a successful update operation.");
+
+    let raw = db
+        .collection::<wither::bson::Document>(User::COLLECTION_NAME)
+        .find_one(doc! {"_id": user.id}, None)
+        .await
+        .expect("Expected a successful find operation.")
+        .expect("Expected the document to exist.");
+    assert_eq!(raw.get_str("email"), Ok("test2@test.com"));
+    assert_eq!(raw.get_i32("login_count"), Ok(5));
+}
+
+#[tokio::test]
+async fn model_update_self_should_require_an_id() {
+    let fixture = Fixture::new().await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: String::from("test@test.com") };
+
+    let err = user
+        .update_self(&db, |user| user.email = String::from("test2@test.com"))
+        .await
+        .expect_err("Expected an errored update operation.");
+
+    assert!(matches!(err, wither::WitherError::ModelIdRequiredForOperation));
+    assert_eq!(user.email, "test@test.com");
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model.delete //////////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_delete_should_delete_model_instance() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+
+    let presave = User::collection(&db).count_documents(None, None).await.unwrap();
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    let postsave = User::collection(&db).count_documents(None, None).await.unwrap();
+    user.delete(&db).await.unwrap();
+    let postdelete = User::collection(&db).count_documents(None, None).await.unwrap();
+
+    assert_eq!(presave, 0);
+    assert_eq!(postsave, 1);
+    assert_eq!(postdelete, 0);
+    assert!(presave != postsave);
+    assert!(postsave != postdelete);
+}
+
+#[tokio::test]
+async fn model_delete_expecting_one_should_error_when_nothing_was_deleted() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+
+    user.delete_expecting_one(&db)
+        .await
+        .expect("Expected a successful delete operation.");
+    let err = user
+        .delete_expecting_one(&db)
+        .await
+        .expect_err("Expected an errored delete operation.");
+
+    assert_eq!(User::collection(&db).count_documents(None, None).await.unwrap(), 0);
+    assert_eq!(err, wither::WitherError::DocumentNotFound(User::COLLECTION_NAME.to_string()));
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model::delete_one /////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_delete_one_should_delete_only_the_first_matching_document() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    let mut user2 = User { id: None, email: "test2@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    user2.save(&db, None).await.expect("Expected a successful save operation.");
+
+    let res = User::delete_one(&db, doc! {"email": {"$exists": true}}, None)
+        .await
+        .expect("Expected a successful delete operation.");
+    let postdelete = User::collection(&db).count_documents(None, None).await.unwrap();
+
+    assert_eq!(res.deleted_count, 1);
+    assert_eq!(postdelete, 1);
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model.delete_many /////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_delete_many_should_delete_all_documents() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    let mut user2 = User { id: None, email: "test2@test.com".to_string() };
+
+    let presave = User::collection(&db).count_documents(None, None).await.unwrap();
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    user2.save(&db, None).await.expect("Expected a successful save operation.");
+    let postsave = User::collection(&db).count_documents(None, None).await.unwrap();
+    let delete_result = User::delete_many(&db, doc! {}, None).await.unwrap();
+    let postdelete = User::collection(&db).count_documents(None, None).await.unwrap();
+
+    assert_eq!(presave, 0);
+    assert_eq!(postsave, 2);
+    assert_eq!(postdelete, 0);
+    assert_eq!(delete_result.deleted_count, 2);
+    assert!(presave != postsave);
+    assert!(postsave != postdelete);
+}
+
+#[tokio::test]
+async fn model_delete_many_should_delete_all_filtered_documents() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    let mut user2 = User { id: None, email: "test2@test.com".to_string() };
+
+    let presave = User::collection(&db).count_documents(None, None).await.unwrap();
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    user2.save(&db, None).await.expect("Expected a successful save operation.");
+    let postsave = User::collection(&db).count_documents(None, None).await.unwrap();
+    let delete_result = User::delete_many(&db, doc! { "email": "test@test.com".to_string() }, None)
+        .await
+        .unwrap();
+    let postdelete = User::collection(&db).count_documents(None, None).await.unwrap();
+
+    let mut remaining_users_from_db: Vec<_> = User::find(&db, None, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .collect()
+        .await;
+    let remaining_user_from_db = remaining_users_from_db.pop().unwrap();
+    assert!(remaining_user_from_db.is_ok());
+
+    let remaining_user_from_db = remaining_user_from_db.unwrap();
+
+    assert_eq!(presave, 0);
+    assert_eq!(postsave, 2);
+    assert_eq!(postdelete, 1);
+    assert_eq!(delete_result.deleted_count, 1);
+    assert!(presave != postsave);
+    assert!(postsave != postdelete);
+    assert_eq!(user2.email, remaining_user_from_db.email);
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model::sync ///////////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_sync_should_create_expected_indices_on_collection() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+
+    // There should be no indexes now
+    let before_indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+    assert!(before_indexes.is_empty());
+
+    // It should sync the model
+    IndexTestV1::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
+
+    // After the sync there should be the expected indexes
+    let after_indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+    let index_model = after_indexes.get("i_1").expect("Should have index: `i_1`");
+    let index_key_value = index_model
+        .keys
+        .get("i")
+        .expect("Should have key `i`")
+        .as_i32()
+        .expect("`i` should be of type Int32");
+
+    assert_eq!(index_key_value, 1);
+
+    let option_name_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("name")
+        .expect("Should have name option")
+        .as_str()
+        .expect("Should be a valid string");
+
+    assert_eq!(option_name_value, "i_1");
+}
+
+#[tokio::test]
+async fn model_sync_should_not_modify_indexes() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+
+    // There should be no indexes now
+    let before_indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+    assert!(before_indexes.is_empty());
+
+    IndexTestV1::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
+
+    // After the sync there should be the expected indexes
+    let after_indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+    let index_model = after_indexes.get("i_1").expect("Should have index: `i_1`");
+    let index_key_value = index_model
+        .keys
+        .get("i")
+        .expect("Should have key `i`")
+        .as_i32()
+        .expect("`i` should be of type Int32");
+
+    assert_eq!(index_key_value, 1);
+
+    let option_name_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("name")
+        .expect("Should have name option")
+        .as_str()
+        .expect("Should be a valid string");
+
+    assert_eq!(option_name_value, "i_1");
+
+    IndexTestV1::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
+
+    // After the sync there should be the expected indexes
+    let after_indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+    let index_model = after_indexes.get("i_1").expect("Should have index: `i_1`");
+    let index_key_value = index_model
+        .keys
+        .get("i")
+        .expect("Should have key `i`")
+        .as_i32()
+        .expect("`i` should be of type Int32");
+
+    assert_eq!(index_key_value, 1);
+
+    let option_name_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("name")
+        .expect("Should have name option")
+        .as_str()
+        .expect("Should be a valid string");
+
+    assert_eq!(option_name_value, "i_1");
+}
+
+#[tokio::test]
+async fn model_sync_should_modify_indexes_v1_to_v2() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+
+    // There should be no indexes now
+    let before_indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+    assert!(before_indexes.is_empty());
+
+    IndexTestV1::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
+
+    // After the sync there should be the expected indexes
+    let after_indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+    let index_model = after_indexes.get("i_1").expect("Should have index: `i_1`");
+    let index_key_value = index_model
+        .keys
+        .get("i")
+        .expect("Should have key `i`")
+        .as_i32()
+        .expect("`i` should be of type Int32");
+
+    assert_eq!(index_key_value, 1);
+
+    let option_name_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("name")
+        .expect("Should have name option")
+        .as_str()
+        .expect("Should be a valid string");
+
+    assert_eq!(option_name_value, "i_1");
+
+    IndexTestV2::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
+
+    // After the sync there should be the expected indexes
+    let after_indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+    let index_model = after_indexes.get("i_-1").expect("Should have index: `i_-1`");
+    let index_key_value = index_model
+        .keys
+        .get("i")
+        .expect("Should have key `i`")
+        .as_i32()
+        .expect("`i` should be of type Int32");
+
+    assert_eq!(index_key_value, -1);
+
+    let option_name_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("name")
+        .expect("Should have name option")
+        .as_str()
+        .expect("Should be a valid string");
+
+    assert_eq!(option_name_value, "i_-1");
+}
+
+#[tokio::test]
+async fn model_sync_should_modify_indexes_v2_to_v3() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+
+    // There should be no indexes now
+    let before_indexes: HashMap<String, IndexModel> = IndexTestV2::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+    assert!(before_indexes.is_empty());
+
+    IndexTestV2::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
+
+    // After the sync there should be the expected indexes
     let after_indexes: HashMap<String, IndexModel> = IndexTestV2::get_current_indexes(&db)
         .await
         .expect("error getting current indexes");
-    let index_model = after_indexes.get("i_-1").expect("Should have index: `i_-1`");
-    let index_key_value = index_model
-        .keys
-        .get("i")
-        .expect("Should have key `i`")
-        .as_i32()
-        .expect("`i` should be of type Int32");
+    let index_model = after_indexes.get("i_-1").expect("Should have index: `i_-1`");
+    let index_key_value = index_model
+        .keys
+        .get("i")
+        .expect("Should have key `i`")
+        .as_i32()
+        .expect("`i` should be of type Int32");
+
+    assert_eq!(index_key_value, -1);
+
+    let option_name_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("name")
+        .expect("Should have name option")
+        .as_str()
+        .expect("Should be a valid string");
+
+    assert_eq!(option_name_value, "i_-1");
+
+    IndexTestV3::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
+
+    // After the sync there should be the expected indexes
+    let after_indexes: HashMap<String, IndexModel> = IndexTestV3::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+    let index_model = after_indexes.get("i_-1").expect("Should have index: `i_-1`");
+    let index_key_value = index_model
+        .keys
+        .get("i")
+        .expect("Should have key `i`")
+        .as_i32()
+        .expect("`i` should be of type Int32");
+
+    assert_eq!(index_key_value, -1);
+
+    let option_name_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("name")
+        .expect("Should have name option")
+        .as_str()
+        .expect("Should be a valid string");
+
+    assert_eq!(option_name_value, "i_-1");
+
+    let option_unique_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("unique")
+        .expect("Should have unique option")
+        .as_bool()
+        .expect("Should be a valid boolean");
+
+    assert!(option_unique_value);
+}
+
+#[tokio::test]
+async fn model_sync_should_modify_indexes_v3_to_v4() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+
+    // There should be no indexes now
+    let before_indexes: HashMap<String, IndexModel> = IndexTestV3::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+    assert!(before_indexes.is_empty());
+
+    IndexTestV3::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
+
+    // After the sync there should be the expected indexes
+    let after_indexes: HashMap<String, IndexModel> = IndexTestV3::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+    let index_model = after_indexes.get("i_-1").expect("Should have index: `i_-1`");
+    let index_key_value = index_model
+        .keys
+        .get("i")
+        .expect("Should have key `i`")
+        .as_i32()
+        .expect("`i` should be of type Int32");
+
+    assert_eq!(index_key_value, -1);
+
+    let option_name_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("name")
+        .expect("Should have name option")
+        .as_str()
+        .expect("Should be a valid string");
+
+    assert_eq!(option_name_value, "i_-1");
+
+    let option_unique_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("unique")
+        .expect("Should have unique option")
+        .as_bool()
+        .expect("Should be a valid boolean");
+
+    assert!(option_unique_value);
+
+    IndexTestV4::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
+
+    // After the sync there should be the expected indexes
+    let after_indexes: HashMap<String, IndexModel> = IndexTestV4::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+    let index_model = after_indexes.get("i_-1").expect("Should have index: `i_-1`");
+    let index_key_value = index_model
+        .keys
+        .get("i")
+        .expect("Should have key `i`")
+        .as_i32()
+        .expect("`i` should be of type Int32");
+
+    assert_eq!(index_key_value, -1);
+
+    let option_name_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("name")
+        .expect("Should have name option")
+        .as_str()
+        .expect("Should be a valid string");
+
+    assert_eq!(option_name_value, "i_-1");
+
+    let option_unique_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("unique")
+        .expect("Should have unique option")
+        .as_bool()
+        .expect("Should be a valid boolean");
+
+    assert!(option_unique_value);
+
+    let option_background_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("background")
+        .expect("Should have background option")
+        .as_bool()
+        .expect("Should be a valid boolean");
+
+    assert!(option_background_value);
+}
+
+#[tokio::test]
+async fn model_sync_should_modify_indexes_v4_to_v5() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+
+    IndexTestV4::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
+
+    // After the sync there should be the expected indexes
+    let after_indexes: HashMap<String, IndexModel> = IndexTestV4::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+    let index_model = after_indexes.get("i_-1").expect("Should have index: `i_-1`");
+    let index_key_value = index_model
+        .keys
+        .get("i")
+        .expect("Should have key `i`")
+        .as_i32()
+        .expect("`i` should be of type Int32");
+
+    assert_eq!(index_key_value, -1);
+
+    let option_name_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("name")
+        .expect("Should have name option")
+        .as_str()
+        .expect("Should be a valid string");
+
+    assert_eq!(option_name_value, "i_-1");
+
+    let option_unique_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("unique")
+        .expect("Should have unique option")
+        .as_bool()
+        .expect("Should be a valid boolean");
+
+    assert!(option_unique_value);
+
+    let option_background_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("background")
+        .expect("Should have background option")
+        .as_bool()
+        .expect("Should be a valid boolean");
+
+    assert!(option_background_value);
+    IndexTestV4::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
+
+    // After the sync there should be the expected indexes
+    let after_indexes: HashMap<String, IndexModel> = IndexTestV4::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+    let index_model = after_indexes.get("i_-1").expect("Should have index: `i_-1`");
+    let index_key_value = index_model
+        .keys
+        .get("i")
+        .expect("Should have key `i`")
+        .as_i32()
+        .expect("`i` should be of type Int32");
+
+    assert_eq!(index_key_value, -1);
+
+    let option_name_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("name")
+        .expect("Should have name option")
+        .as_str()
+        .expect("Should be a valid string");
+
+    assert_eq!(option_name_value, "i_-1");
+
+    let option_unique_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("unique")
+        .expect("Should have unique option")
+        .as_bool()
+        .expect("Should be a valid boolean");
+
+    assert!(option_unique_value);
+
+    let option_background_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("background")
+        .expect("Should have background option")
+        .as_bool()
+        .expect("Should be a valid boolean");
+
+    assert!(option_background_value);
+}
+
+#[tokio::test]
+async fn model_sync_should_modify_indexes_v5_to_v6() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+
+    IndexTestV5::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
+
+    // After the sync there should be the expected indexes
+    let after_indexes: HashMap<String, IndexModel> = IndexTestV5::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+    let index_model = after_indexes.get("i_-1").expect("Should have index: `i_-1`");
+    let index_key_value = index_model
+        .keys
+        .get("i")
+        .expect("Should have key `i`")
+        .as_i32()
+        .expect("`i` should be of type Int32");
+
+    assert_eq!(index_key_value, -1);
+
+    let option_name_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("name")
+        .expect("Should have name option")
+        .as_str()
+        .expect("Should be a valid string");
+
+    assert_eq!(option_name_value, "i_-1");
+
+    let option_unique_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("unique")
+        .expect("Should have unique option")
+        .as_bool()
+        .expect("Should be a valid boolean");
+
+    assert!(option_unique_value);
+
+    let option_background_value = index_model
+        .options
+        .as_ref()
+        .expect("options should not be empty")
+        .get("background")
+        .expect("Should have background option")
+        .as_bool()
+        .expect("Should be a valid boolean");
+
+    assert!(option_background_value);
+
+    IndexTestV6::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
+
+    // After the sync there should be the expected indexes
+    let after_indexes: HashMap<String, IndexModel> = IndexTestV6::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+
+    assert!(after_indexes.is_empty());
+}
+
+#[tokio::test]
+async fn model_sync_should_key_indexes_on_explicit_names() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+
+    IndexTestNamed::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
+    IndexTestNamed::sync(&db)
+        .await
+        .expect("Expected a successful repeated sync operation.");
+
+    let after_indexes: HashMap<String, IndexModel> = IndexTestNamed::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+
+    assert_eq!(after_indexes.len(), 1);
+    assert!(after_indexes.contains_key("custom-i"));
+}
+
+#[tokio::test]
+async fn model_sync_should_error_on_duplicate_index_names() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+
+    let err = IndexTestDuplicate::sync(&db)
+        .await
+        .expect_err("Expected an errored sync operation.");
+    let after_indexes: HashMap<String, IndexModel> = IndexTestDuplicate::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+
+    assert!(matches!(err, wither::WitherError::DuplicateIndexName(name) if name == "i_1"));
+    assert!(after_indexes.is_empty());
+}
+
+#[tokio::test]
+async fn model_sync_with_should_create_indexes_with_commit_quorum() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+    let mut options = SyncOptions::default();
+    options.commit_quorum = Some("votingMembers".into());
+    options.log_progress = true;
+
+    IndexTestV1::sync_with(&db, options)
+        .await
+        .expect("Expected a successful sync operation.");
+    let after_indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+
+    assert!(after_indexes.contains_key("i_1"));
+}
+
+#[tokio::test]
+async fn model_indexes_in_sync_should_detect_drift_without_modifying_indexes() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+
+    let before_sync = IndexTestV1::indexes_in_sync(&db)
+        .await
+        .expect("Expected a successful drift check.");
+    IndexTestV1::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
+    let after_sync = IndexTestV1::indexes_in_sync(&db)
+        .await
+        .expect("Expected a successful drift check.");
+    let other_version = IndexTestV2::indexes_in_sync(&db)
+        .await
+        .expect("Expected a successful drift check.");
+    let after_indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
+
+    assert!(!before_sync);
+    assert!(after_sync);
+    assert!(!other_version);
+    assert!(after_indexes.contains_key("i_1"));
+    assert!(!after_indexes.contains_key("i_-1"));
+}
+
+#[tokio::test]
+async fn model_ensure_index_should_create_only_the_given_index() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+    IndexTestV1::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
+
+    IndexTestV1::ensure_index(&db, IndexModel::new(doc! {"report": 1}, None))
+        .await
+        .expect("Expected a successful ensure index operation.");
+    IndexTestV1::ensure_index(&db, IndexModel::new(doc! {"report": 1}, None))
+        .await
+        .expect("Expected ensuring an existing index to be a no-op.");
+    let indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
+        .await
+        .expect("error getting current indexes");
 
-    assert_eq!(index_key_value, -1);
+    assert!(indexes.contains_key("i_1"));
+    assert!(indexes.contains_key("report_1"));
+    assert!(!IndexTestV1::indexes_in_sync(&db)
+        .await
+        .expect("Expected a successful drift check."));
+}
 
-    let option_name_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("name")
-        .expect("Should have name option")
-        .as_str()
-        .expect("Should be a valid string");
+#[tokio::test]
+async fn model_ensure_index_should_report_index_name_conflicts() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+    IndexTestV1::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
 
-    assert_eq!(option_name_value, "i_-1");
+    let err = IndexTestV1::ensure_index(&db, IndexModel::new(doc! {"i": 1}, Some(doc! {"name": "custom-i"})))
+        .await
+        .expect_err("Expected an errored ensure index operation.");
 
-    IndexTestV3::sync(&db)
+    assert_eq!(
+        err,
+        wither::WitherError::IndexNameConflict { aspired: "custom-i".into(), existing: "i_1".into() }
+    );
+}
+
+#[tokio::test]
+async fn model_sync_with_should_adopt_renamed_indexes() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+    IndexTestV1::ensure_index(&db, IndexModel::new(doc! {"i": 1}, Some(doc! {"name": "custom-i"})))
         .await
-        .expect("Expected a successful sync operation.");
+        .expect("Expected a successful ensure index operation.");
+    let mut options = SyncOptions::default();
+    options.adopt_renamed_indexes = true;
 
-    // After the sync there should be the expected indexes
-    let after_indexes: HashMap<String, IndexModel> = IndexTestV3::get_current_indexes(&db)
+    IndexTestV1::sync_with(&db, options)
+        .await
+        .expect("Expected a successful sync operation.");
+    let indexes: HashMap<String, IndexModel> = IndexTestV1::get_current_indexes(&db)
         .await
         .expect("error getting current indexes");
-    let index_model = after_indexes.get("i_-1").expect("Should have index: `i_-1`");
-    let index_key_value = index_model
-        .keys
-        .get("i")
-        .expect("Should have key `i`")
-        .as_i32()
-        .expect("`i` should be of type Int32");
 
-    assert_eq!(index_key_value, -1);
+    assert_eq!(indexes.len(), 1);
+    assert!(indexes.contains_key("custom-i"));
+}
 
-    let option_name_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("name")
-        .expect("Should have name option")
-        .as_str()
-        .expect("Should be a valid string");
+async fn get_validation_options(db: &wither::mongodb::Database) -> Option<(Option<Document>, Option<ValidationLevel>)> {
+    let spec = db
+        .list_collections(doc! {"name": ValidatedUser::COLLECTION_NAME}, None)
+        .await
+        .expect("Expected a successful collection listing.")
+        .try_next()
+        .await
+        .expect("Expected a successful cursor read.")?;
+    Some((spec.options.validator, spec.options.validation_level))
+}
 
-    assert_eq!(option_name_value, "i_-1");
+#[tokio::test]
+async fn model_sync_should_create_collection_with_validator() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
 
-    let option_unique_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("unique")
-        .expect("Should have unique option")
-        .as_bool()
-        .expect("Should be a valid boolean");
+    ValidatedUser::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
+    let (validator, level) = get_validation_options(&db)
+        .await
+        .expect("Expected the collection to be created.");
+    let err = ValidatedUser { id: None, email: None }
+        .save(&db, None)
+        .await
+        .expect_err("Expected the validator to reject the document.");
 
-    assert!(option_unique_value);
+    assert_eq!(validator, ValidatedUser::validator());
+    assert_eq!(level, Some(ValidationLevel::Moderate));
+    assert!(matches!(err, wither::WitherError::Mongo(_)));
 }
 
 #[tokio::test]
-async fn model_sync_should_modify_indexes_v3_to_v4() {
+async fn model_sync_should_apply_validator_to_existing_collection() {
     let fixture = Fixture::new().await.with_dropped_database().await;
     let db = fixture.get_db();
+    db.create_collection(ValidatedUser::COLLECTION_NAME, None)
+        .await
+        .expect("Expected a successful collection creation.");
 
-    // There should be no indexes now
-    let before_indexes: HashMap<String, IndexModel> = IndexTestV3::get_current_indexes(&db)
+    ValidatedUser::sync(&db)
         .await
-        .expect("error getting current indexes");
-    assert!(before_indexes.is_empty());
+        .expect("Expected a successful sync operation.");
+    ValidatedUser::sync(&db)
+        .await
+        .expect("Expected a successful re-sync operation.");
+    let (validator, level) = get_validation_options(&db)
+        .await
+        .expect("Expected the collection to exist.");
+    let mut user = ValidatedUser { id: None, email: Some("test@test.com".to_string()) };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
 
-    IndexTestV3::sync(&db)
+    assert_eq!(validator, ValidatedUser::validator());
+    assert_eq!(level, Some(ValidationLevel::Moderate));
+}
+
+async fn get_capped_options(db: &wither::mongodb::Database) -> Option<(bool, Option<u64>, Option<u64>)> {
+    let spec = db
+        .list_collections(doc! {"name": "cappedTest"}, None)
+        .await
+        .expect("Expected a successful collection listing.")
+        .try_next()
+        .await
+        .expect("Expected a successful cursor read.")?;
+    Some((spec.options.capped.unwrap_or(false), spec.options.size, spec.options.max))
+}
+
+#[tokio::test]
+async fn model_sync_should_create_capped_collection() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+
+    CappedTestV1::sync(&db)
         .await
         .expect("Expected a successful sync operation.");
 
-    // After the sync there should be the expected indexes
-    let after_indexes: HashMap<String, IndexModel> = IndexTestV3::get_current_indexes(&db)
+    assert_eq!(get_capped_options(&db).await, Some((true, Some(4096), Some(10))));
+}
+
+#[tokio::test]
+async fn model_sync_should_not_recreate_capped_collection_by_default() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+    CappedTestV1::sync(&db)
         .await
-        .expect("error getting current indexes");
-    let index_model = after_indexes.get("i_-1").expect("Should have index: `i_-1`");
-    let index_key_value = index_model
-        .keys
-        .get("i")
-        .expect("Should have key `i`")
-        .as_i32()
-        .expect("`i` should be of type Int32");
+        .expect("Expected a successful sync operation.");
 
-    assert_eq!(index_key_value, -1);
+    CappedTestV2::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
 
-    let option_name_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("name")
-        .expect("Should have name option")
-        .as_str()
-        .expect("Should be a valid string");
+    assert_eq!(get_capped_options(&db).await, Some((true, Some(4096), Some(10))));
+}
 
-    assert_eq!(option_name_value, "i_-1");
+#[tokio::test]
+async fn model_sync_with_should_recreate_empty_capped_collection_on_drift() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+    CappedTestV1::sync(&db)
+        .await
+        .expect("Expected a successful sync operation.");
+    let mut options = SyncOptions::default();
+    options.recreate_empty_capped = true;
 
-    let option_unique_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("unique")
-        .expect("Should have unique option")
-        .as_bool()
-        .expect("Should be a valid boolean");
+    CappedTestV2::sync_with(&db, options)
+        .await
+        .expect("Expected a successful sync operation.");
 
-    assert!(option_unique_value);
+    assert_eq!(get_capped_options(&db).await, Some((true, Some(8192), None)));
+}
 
-    IndexTestV4::sync(&db)
+#[tokio::test]
+async fn model_sync_with_should_not_recreate_non_empty_capped_collection_on_drift() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+    CappedTestV1::sync(&db)
         .await
         .expect("Expected a successful sync operation.");
+    let mut model = CappedTestV1 { id: None, i: "a".to_string() };
+    model.save(&db, None).await.expect("Expected a successful save operation.");
+    let mut options = SyncOptions::default();
+    options.recreate_empty_capped = true;
 
-    // After the sync there should be the expected indexes
-    let after_indexes: HashMap<String, IndexModel> = IndexTestV4::get_current_indexes(&db)
+    CappedTestV2::sync_with(&db, options)
         .await
-        .expect("error getting current indexes");
-    let index_model = after_indexes.get("i_-1").expect("Should have index: `i_-1`");
-    let index_key_value = index_model
-        .keys
-        .get("i")
-        .expect("Should have key `i`")
-        .as_i32()
-        .expect("`i` should be of type Int32");
+        .expect("Expected a successful sync operation.");
 
-    assert_eq!(index_key_value, -1);
+    assert_eq!(get_capped_options(&db).await, Some((true, Some(4096), Some(10))));
+    assert_eq!(
+        CappedTestV1::count(&db, doc! {})
+            .await
+            .expect("Expected a successful count."),
+        1
+    );
+}
 
-    let option_name_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("name")
-        .expect("Should have name option")
-        .as_str()
-        .expect("Should be a valid string");
+//////////////////////////////////////////////////////////////////////////////
+// Model::collection_named ///////////////////////////////////////////////////
 
-    assert_eq!(option_name_value, "i_-1");
+#[tokio::test]
+async fn model_in_methods_should_target_the_named_collection() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
 
-    let option_unique_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("unique")
-        .expect("Should have unique option")
-        .as_bool()
-        .expect("Should be a valid boolean");
+    user.save_in(&db, "users_tenantA", None)
+        .await
+        .expect("Expected a successful save operation.");
+    let user = user
+        .update_in(&db, "users_tenantA", None, doc! {"$set": {"email": "new@test.com"}}, None)
+        .await
+        .expect("Expected a successful update operation.");
+    let found = User::find_one_in(&db, "users_tenantA", doc! {"_id": user.id.unwrap()}, None)
+        .await
+        .expect("Expected a successful lookup.");
+    let found_all: Vec<User> = User::find_in(&db, "users_tenantA", None, None)
+        .await
+        .expect("Expected a successful lookup.")
+        .try_collect()
+        .await
+        .expect("Expected a successful decode.");
+    let default_count = User::count(&db, doc! {}).await.unwrap();
+    let res = user
+        .delete_in(&db, "users_tenantA")
+        .await
+        .expect("Expected a successful delete operation.");
 
-    assert!(option_unique_value);
+    assert_eq!(found.as_ref(), Some(&user));
+    assert_eq!(found_all, vec![user.clone()]);
+    assert_eq!(default_count, 0);
+    assert_eq!(res.deleted_count, 1);
+}
+
+#[tokio::test]
+async fn model_update_in_should_report_named_collection_when_not_found() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+    let user = User::new_unsaved().with_id(wither::bson::oid::ObjectId::new());
+
+    let err = user
+        .update_in(&db, "users_tenantA", None, doc! {"$set": {"email": "new@test.com"}}, None)
+        .await
+        .expect_err("Expected an errored update operation.");
+
+    assert!(matches!(err, wither::WitherError::DocumentNotFound(name) if name == "users_tenantA"));
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model::drop_collection ////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_drop_collection_should_remove_all_documents() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+
+    let fixture = fixture.with_empty_collections().await;
+    let db = fixture.get_db();
+    let postdrop = User::collection(&db).count_documents(None, None).await.unwrap();
+    let indexes = User::get_current_indexes(&db).await.unwrap();
+
+    assert_eq!(postdrop, 0);
+    assert!(indexes.is_empty());
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model::collection_exists //////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_collection_exists_should_reflect_collection_creation() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+
+    let before = User::collection_exists(&db).await.expect("Expected a successful check.");
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    let after = User::collection_exists(&db).await.expect("Expected a successful check.");
+
+    assert!(!before);
+    assert!(after);
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Model::index_info /////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn model_index_info_should_summarize_current_indexes() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
 
-    let option_background_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("background")
-        .expect("Should have background option")
-        .as_bool()
-        .expect("Should be a valid boolean");
+    let info = User::index_info(&db)
+        .await
+        .expect("Expected a successful index info request.");
 
-    assert!(option_background_value);
+    assert_eq!(info.len(), 1);
+    assert_eq!(info[0].name, "unique-email");
+    assert_eq!(info[0].keys, doc! {"email": 1});
+    assert!(info[0].unique);
+    assert_eq!(info[0].ttl, None);
 }
 
+//////////////////////////////////////////////////////////////////////////////
+// Model::drop_indexes ///////////////////////////////////////////////////////
+
 #[tokio::test]
-async fn model_sync_should_modify_indexes_v4_to_v5() {
-    let fixture = Fixture::new().await.with_dropped_database().await;
+async fn model_drop_indexes_should_remove_all_non_id_indexes() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
     let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
 
-    IndexTestV4::sync(&db)
+    User::drop_indexes(&db)
         .await
-        .expect("Expected a successful sync operation.");
+        .expect("Expected a successful drop indexes operation.");
+    let indexes = User::get_current_indexes(&db).await.unwrap();
+    let count = User::collection(&db).count_documents(None, None).await.unwrap();
 
-    // After the sync there should be the expected indexes
-    let after_indexes: HashMap<String, IndexModel> = IndexTestV4::get_current_indexes(&db)
+    assert!(indexes.is_empty());
+    assert_eq!(count, 1);
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Migrating::migrate ////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn migrating_migrate_should_apply_interval_migrations() {
+    let fixture = Fixture::new()
         .await
-        .expect("error getting current indexes");
-    let index_model = after_indexes.get("i_-1").expect("Should have index: `i_-1`");
-    let index_key_value = index_model
-        .keys
-        .get("i")
-        .expect("Should have key `i`")
-        .as_i32()
-        .expect("`i` should be of type Int32");
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
 
-    assert_eq!(index_key_value, -1);
+    let reports = User::migrate(&db).await.expect("Expected a successful migration.");
+    let migrated = User::collection(&db)
+        .count_documents(doc! {"testfield": "test"}, None)
+        .await
+        .unwrap();
 
-    let option_name_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("name")
-        .expect("Should have name option")
-        .as_str()
-        .expect("Should be a valid string");
+    let pipeline_migrated = User::collection(&db)
+        .count_documents(doc! {"testpipelinefield": "test@test.com"}, None)
+        .await
+        .unwrap();
 
-    assert_eq!(option_name_value, "i_-1");
+    assert_eq!(migrated, 1);
+    assert_eq!(pipeline_migrated, 1);
+    assert_eq!(reports.len(), 2);
+    assert_eq!(
+        reports[0],
+        MigrationReport {
+            name: "test-migration".to_string(),
+            matched_count: 1,
+            modified_count: 1,
+            skipped: false
+        }
+    );
+}
 
-    let option_unique_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("unique")
-        .expect("Should have unique option")
-        .as_bool()
-        .expect("Should be a valid boolean");
+#[tokio::test]
+async fn migrating_migration_status_should_report_without_modifying_documents() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    let expired = IntervalMigration {
+        name: String::from("expired-migration"),
+        threshold: chrono::Utc.ymd(2000, 1, 1).and_hms(1, 0, 0),
+        filter: doc! {},
+        set: Some(doc! {"expiredfield": "test"}),
+        unset: None,
+        batch_size: None,
+        write_concern: None,
+    };
 
-    assert!(option_unique_value);
+    let statuses = User::migration_status(&db)
+        .await
+        .expect("Expected a successful status check.");
+    let expired_status = Migration::<User>::status(&expired, &User::collection(&db))
+        .await
+        .expect("Expected a successful status check.");
+    let migrated = User::collection(&db)
+        .count_documents(doc! {"testfield": "test"}, None)
+        .await
+        .unwrap();
 
-    let option_background_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("background")
-        .expect("Should have background option")
-        .as_bool()
-        .expect("Should be a valid boolean");
+    assert_eq!(migrated, 0);
+    assert_eq!(
+        statuses,
+        vec![
+            MigrationStatus {
+                name: "test-migration".to_string(),
+                pending: true,
+                estimated_count: Some(1)
+            },
+            MigrationStatus {
+                name: "test-pipeline-migration".to_string(),
+                pending: true,
+                estimated_count: Some(1)
+            },
+        ]
+    );
+    assert_eq!(
+        expired_status,
+        MigrationStatus {
+            name: "expired-migration".to_string(),
+            pending: false,
+            estimated_count: None
+        }
+    );
+}
 
-    assert!(option_background_value);
-    IndexTestV4::sync(&db)
+#[tokio::test]
+async fn interval_migration_should_apply_updates_in_batches() {
+    let fixture = Fixture::new()
         .await
-        .expect("Expected a successful sync operation.");
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    for idx in 0..5 {
+        let mut user = User { id: None, email: format!("test-{}@test.com", idx) };
+        user.save(&db, None).await.expect("Expected a successful save operation.");
+    }
+    let migration = IntervalMigration {
+        name: String::from("test-batched-migration"),
+        threshold: chrono::Utc.ymd(2100, 1, 1).and_hms(1, 0, 0),
+        filter: doc! {"email": doc!{"$exists": true}},
+        set: Some(doc! {"testbatchfield": "test"}),
+        unset: None,
+        batch_size: Some(2),
+        write_concern: None,
+    };
+
+    let report = migration
+        .execute(&User::collection(&db))
+        .await
+        .expect("Expected a successful migration.");
+    let migrated = User::collection(&db)
+        .count_documents(doc! {"testbatchfield": "test"}, None)
+        .await
+        .unwrap();
 
-    // After the sync there should be the expected indexes
-    let after_indexes: HashMap<String, IndexModel> = IndexTestV4::get_current_indexes(&db)
+    assert_eq!(migrated, 5);
+    assert_eq!(
+        report,
+        MigrationReport {
+            name: "test-batched-migration".to_string(),
+            matched_count: 5,
+            modified_count: 5,
+            skipped: false
+        }
+    );
+}
+
+#[tokio::test]
+async fn interval_migration_execute_forced_should_ignore_threshold() {
+    let fixture = Fixture::new()
         .await
-        .expect("error getting current indexes");
-    let index_model = after_indexes.get("i_-1").expect("Should have index: `i_-1`");
-    let index_key_value = index_model
-        .keys
-        .get("i")
-        .expect("Should have key `i`")
-        .as_i32()
-        .expect("`i` should be of type Int32");
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let mut user = User { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+    let migration = IntervalMigration::builder()
+        .name("test-expired-migration")
+        .threshold(chrono::Utc.ymd(2000, 1, 1).and_hms(1, 0, 0))
+        .set(doc! {"testexpiredfield": "test"})
+        .build()
+        .unwrap();
+    let coll = User::collection(&db);
 
-    assert_eq!(index_key_value, -1);
+    let skipped = migration.execute(&coll).await.expect("Expected a successful migration.");
+    let forced = migration
+        .execute_forced(&coll)
+        .await
+        .expect("Expected a successful migration.");
 
-    let option_name_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("name")
-        .expect("Should have name option")
-        .as_str()
-        .expect("Should be a valid string");
+    assert_eq!(skipped, MigrationReport::skipped("test-expired-migration"));
+    assert_eq!(
+        forced,
+        MigrationReport {
+            name: "test-expired-migration".to_string(),
+            matched_count: 1,
+            modified_count: 1,
+            skipped: false
+        }
+    );
+}
 
-    assert_eq!(option_name_value, "i_-1");
+#[tokio::test]
+async fn rename_migration_should_rename_fields() {
+    let fixture = Fixture::new()
+        .await
+        .with_dropped_database()
+        .await
+        .with_synced_models()
+        .await;
+    let db = fixture.get_db();
+    let docs = User::collection(&db).clone_with_type::<wither::bson::Document>();
+    docs.insert_many(
+        vec![
+            doc! {"email": "a@test.com", "legacyfield": "a"},
+            doc! {"email": "b@test.com", "legacyfield": "b"},
+            doc! {"email": "c@test.com"},
+        ],
+        None,
+    )
+    .await
+    .expect("Expected a successful insert operation.");
+    let migration = RenameMigration {
+        name: String::from("test-rename-migration"),
+        threshold: chrono::Utc.ymd(2100, 1, 1).and_hms(1, 0, 0),
+        filter: doc! {},
+        from: String::from("legacyfield"),
+        to: String::from("renamedfield"),
+        write_concern: None,
+    };
+
+    let report = migration
+        .execute(&User::collection(&db))
+        .await
+        .expect("Expected a successful migration.");
+    let legacy = docs
+        .count_documents(doc! {"legacyfield": {"$exists": true}}, None)
+        .await
+        .unwrap();
+    let renamed = docs
+        .count_documents(doc! {"renamedfield": {"$in": ["a", "b"]}}, None)
+        .await
+        .unwrap();
 
-    let option_unique_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("unique")
-        .expect("Should have unique option")
-        .as_bool()
-        .expect("Should be a valid boolean");
+    assert_eq!(legacy, 0);
+    assert_eq!(renamed, 2);
+    assert_eq!(
+        report,
+        MigrationReport {
+            name: "test-rename-migration".to_string(),
+            matched_count: 2,
+            modified_count: 2,
+            skipped: false
+        }
+    );
+}
 
-    assert!(option_unique_value);
+#[tokio::test]
+async fn rename_migration_should_error_when_fields_are_not_distinct() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+    let migration = RenameMigration {
+        name: String::from("test-rename-migration"),
+        threshold: chrono::Utc.ymd(2100, 1, 1).and_hms(1, 0, 0),
+        filter: doc! {},
+        from: String::from("email"),
+        to: String::from("email"),
+        write_concern: None,
+    };
 
-    let option_background_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("background")
-        .expect("Should have background option")
-        .as_bool()
-        .expect("Should be a valid boolean");
+    let err = migration
+        .execute(&User::collection(&db))
+        .await
+        .expect_err("Expected an errored migration.");
 
-    assert!(option_background_value);
+    assert_eq!(err, wither::WitherError::MigrationRenameInvalid);
 }
 
 #[tokio::test]
-async fn model_sync_should_modify_indexes_v5_to_v6() {
+async fn migrating_migrate_should_error_when_set_and_unset_are_missing() {
     let fixture = Fixture::new().await.with_dropped_database().await;
     let db = fixture.get_db();
 
-    IndexTestV5::sync(&db)
+    let err = UserModelBadMigrations::migrate(&db)
         .await
-        .expect("Expected a successful sync operation.");
+        .expect_err("Expected an errored migration.");
 
-    // After the sync there should be the expected indexes
-    let after_indexes: HashMap<String, IndexModel> = IndexTestV5::get_current_indexes(&db)
+    assert_eq!(err.to_string(), "One of '$set' or '$unset' must be specified.");
+}
+
+#[tokio::test]
+async fn migrating_migrate_should_create_required_indexes() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+    let mut user = UserModelIndexedMigrations { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
+
+    let reports = UserModelIndexedMigrations::migrate(&db)
         .await
-        .expect("error getting current indexes");
-    let index_model = after_indexes.get("i_-1").expect("Should have index: `i_-1`");
-    let index_key_value = index_model
-        .keys
-        .get("i")
-        .expect("Should have key `i`")
-        .as_i32()
-        .expect("`i` should be of type Int32");
+        .expect("Expected a successful migration.");
+    // Running again must not fail on the already existing index.
+    UserModelIndexedMigrations::migrate(&db)
+        .await
+        .expect("Expected a successful migration.");
+    let indexes = UserModelIndexedMigrations::get_current_indexes(&db).await.unwrap();
 
-    assert_eq!(index_key_value, -1);
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].modified_count, 1);
+    assert!(indexes.contains_key("migration-email"));
+}
 
-    let option_name_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("name")
-        .expect("Should have name option")
-        .as_str()
-        .expect("Should be a valid string");
+#[tokio::test]
+async fn migrating_migrate_should_only_create_required_indexes_of_pending_migrations() {
+    let fixture = Fixture::new().await.with_dropped_database().await;
+    let db = fixture.get_db();
+    let mut user = UserModelExpiredIndexedMigrations { id: None, email: "test@test.com".to_string() };
+    user.save(&db, None).await.expect("Expected a successful save operation.");
 
-    assert_eq!(option_name_value, "i_-1");
+    UserModelExpiredIndexedMigrations::migrate(&db)
+        .await
+        .expect("Expected a successful migration.");
+    let skipped_indexes = UserModelExpiredIndexedMigrations::get_current_indexes(&db).await.unwrap();
+    UserModelExpiredIndexedMigrations::migrate_forced(&db)
+        .await
+        .expect("Expected a successful migration.");
+    let forced_indexes = UserModelExpiredIndexedMigrations::get_current_indexes(&db).await.unwrap();
 
-    let option_unique_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("unique")
-        .expect("Should have unique option")
-        .as_bool()
-        .expect("Should be a valid boolean");
+    assert!(!skipped_indexes.contains_key("migration-email"));
+    assert!(forced_indexes.contains_key("migration-email"));
+}
 
-    assert!(option_unique_value);
+//////////////////////////////////////////////////////////////////////////////
+// ModelExt //////////////////////////////////////////////////////////////////
 
-    let option_background_value = index_model
-        .options
-        .as_ref()
-        .expect("options should not be empty")
-        .get("background")
-        .expect("Should have background option")
-        .as_bool()
-        .expect("Should be a valid boolean");
+#[test]
+fn model_ext_new_unsaved_should_have_no_id() {
+    let user = User::new_unsaved();
 
-    assert!(option_background_value);
+    assert!(user.id.is_none());
+}
 
-    IndexTestV6::sync(&db)
-        .await
-        .expect("Expected a successful sync operation.");
+#[test]
+fn model_ext_with_id_should_set_id() {
+    let id = wither::bson::oid::ObjectId::new();
 
-    // After the sync there should be the expected indexes
-    let after_indexes: HashMap<String, IndexModel> = IndexTestV6::get_current_indexes(&db)
-        .await
-        .expect("error getting current indexes");
+    let user = User::new_unsaved().with_id(id);
 
-    assert!(after_indexes.is_empty());
+    assert_eq!(user.id, Some(id));
 }
@@ -1,13 +1,15 @@
 use darling::FromMeta;
 use inflector::Inflector;
 use proc_macro_error::abort;
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::DeriveInput;
 
 /// The name of the helper attribute used by this derive macro.
 const MODEL_HELPER_ATTR: &str = "model";
 /// An error message indicating the existence of a duplicate attr.
 const DUPLICATE_ATTR_SPEC: &str = "duplicate attr specification";
+/// An error message indicating more than one selection criteria attr.
+const DUPLICATE_SELECTION_CRITERIA_SPEC: &str = "only one of `selection_criteria` or `read_preference` may be specified";
 /// An error message describing the correct form expected for an attribute.
 const META_MUST_BE_KV_PAIR: &str = "this attribute must be specified as a `key=value` pair";
 
@@ -21,8 +23,13 @@ pub(crate) struct MetaModel<'a> {
     collection_name: Option<String>,
     /// A flag to configure if serde checks should be skipped.
     skip_serde_checks: Option<()>,
+    /// The path to the model's ID field, as a sequence of field idents; will default to `id` if
+    /// not specified.
+    id_field: Option<Vec<syn::Ident>>,
     /// All indexes derived on this model.
     indexes: Vec<IndexModelTokens>,
+    /// A flag indicating if a text index has been declared via `text_index`.
+    has_text_index: bool,
     /// The model's read concern; will default to None if not specified.
     ///
     /// NOTE WELL: there is currently an issue with darling's parsing of enums where if the value
@@ -33,7 +40,45 @@ pub(crate) struct MetaModel<'a> {
     pub write_concern: Option<WriteConcern>,
     /// The function which should be called to get the model's selection criteria; will default to
     /// None if not specified.
-    pub selection_criteria: Option<syn::Path>,
+    pub selection_criteria: Option<SelectionCriteria>,
+    /// The model's default index hint, as an expression producing a `Hint`; will default to None
+    /// if not specified.
+    hint: Option<proc_macro2::TokenStream>,
+    /// The model's default maximum query execution time, in milliseconds; will default to None
+    /// if not specified.
+    max_time_ms: Option<u64>,
+    /// A flag to configure if aggregations may write to temporary files by default.
+    allow_disk_use: Option<()>,
+    /// A flag to configure if `save` & `update` should force journaled write concern; will default
+    /// to `true` if not specified.
+    pub save_force_journal: Option<bool>,
+    /// A flag to configure if constants holding the serialized name of each field should be
+    /// generated.
+    field_consts: Option<()>,
+    /// The fields to be taken as arguments by a generated `new` constructor; no constructor is
+    /// generated if not specified.
+    constructor: Option<Vec<syn::Ident>>,
+    /// The field used for optimistic concurrency by `save_versioned`; will default to None if not
+    /// specified.
+    version_field: Option<syn::Ident>,
+    /// The field used to hold the snapshot taken by `mark_clean`; will default to None if not
+    /// specified.
+    snapshot_field: Option<syn::Ident>,
+    /// A flag to configure if the model is read-only, rejecting all writes at runtime.
+    read_only: Option<()>,
+    /// Paths to functions producing additional indexes to be appended to the model's indexes.
+    indexes_from: Vec<syn::Path>,
+    /// The parameters of the model's capped collection; will default to None if not specified.
+    capped: Option<RawCapped>,
+    /// The model's collection validator, as an expression producing a `Document`; will default to
+    /// None if not specified.
+    validator: Option<proc_macro2::TokenStream>,
+    /// The validation level to apply along with the model's validator.
+    validation_level: Option<syn::LitStr>,
+    /// The validation action to apply along with the model's validator.
+    validation_action: Option<syn::LitStr>,
+    /// Compile time warnings to be emitted along with the model impl.
+    warnings: Vec<proc_macro2::TokenStream>,
 }
 
 impl<'a> MetaModel<'a> {
@@ -54,11 +99,28 @@ impl<'a> MetaModel<'a> {
             attrs: input.attrs.as_slice(),
             fields: vec![],
             indexes: vec![],
+            has_text_index: false,
             collection_name: None,
             skip_serde_checks: None,
+            id_field: None,
             read_concern: None,
             write_concern: None,
             selection_criteria: None,
+            hint: None,
+            max_time_ms: None,
+            allow_disk_use: None,
+            save_force_journal: None,
+            field_consts: None,
+            constructor: None,
+            version_field: None,
+            snapshot_field: None,
+            read_only: None,
+            indexes_from: vec![],
+            capped: None,
+            validator: None,
+            validation_level: None,
+            validation_action: None,
+            warnings: vec![],
         };
 
         // Parse attrs for struct-level model attrs.
@@ -67,6 +129,24 @@ impl<'a> MetaModel<'a> {
         inst.extract_model_fields(fields);
         // Validate the model's ID field.
         inst.check_id_field();
+        // Validate the fields of the generated constructor, if any.
+        inst.check_constructor_fields();
+        // Validate the model's version field, if any.
+        inst.check_version_field();
+        // Validate the model's snapshot field, if any.
+        inst.check_snapshot_field();
+        // Validate that validation options are only declared along with a validator.
+        inst.check_validation_options();
+        // Validate the directions & types of index keys.
+        inst.check_index_directions();
+        // Validate combinations of index options.
+        inst.check_index_options();
+        // Validate that at most one text index is declared.
+        inst.check_text_indexes();
+        // Validate index paths against the model's fields.
+        if inst.skip_serde_checks.is_none() {
+            inst.check_index_paths();
+        }
         inst
     }
 
@@ -77,20 +157,171 @@ impl<'a> MetaModel<'a> {
         let read_concern = OptionReadConcern(&self.read_concern);
         let write_concern = OptionWriteConcern(&self.write_concern);
         let selection_criteria = OptionSelectionCriteria(&self.selection_criteria);
+        let save_force_journal = self.save_force_journal.unwrap_or(true);
         let indexes = &self.indexes;
+        let indexes = match self.indexes_from.as_slice() {
+            [] => quote!(vec![#(#indexes),*]),
+            indexes_from => quote! {
+                let mut indexes = vec![#(#indexes),*];
+                #(indexes.extend(#indexes_from());)*
+                indexes
+            },
+        };
+        let warnings = &self.warnings;
+        let field_consts = self.expand_field_consts();
+        let no_overwrite_fields = self.expand_no_overwrite_fields();
+        let constructor = self.expand_constructor();
+        let default_max_time = match self.max_time_ms {
+            Some(max_time_ms) => quote! {
+                /// The model's default maximum execution time for queries.
+                fn default_max_time() -> Option<::std::time::Duration> {
+                    Some(::std::time::Duration::from_millis(#max_time_ms))
+                }
+            },
+            None => quote!(),
+        };
+        let default_allow_disk_use = match self.allow_disk_use {
+            Some(_) => quote! {
+                /// Whether aggregations on this model should be allowed to write to temporary files.
+                fn default_allow_disk_use() -> bool {
+                    true
+                }
+            },
+            None => quote!(),
+        };
+        let default_hint = match &self.hint {
+            Some(hint) => quote! {
+                /// The model's default index hint.
+                fn default_hint() -> Option<wither::mongodb::options::Hint> {
+                    Some(#hint)
+                }
+            },
+            None => quote!(),
+        };
+        let version_field = match &self.version_field {
+            Some(ident) => {
+                let field_name = self.version_field_name(ident);
+                quote! {
+                    /// The serialized name of the model's version field.
+                    fn version_field() -> Option<&'static str> {
+                        Some(#field_name)
+                    }
+                }
+            }
+            None => quote!(),
+        };
+        let snapshot_field = match &self.snapshot_field {
+            Some(ident) => quote! {
+                /// The snapshot of this instance's serialized form.
+                fn snapshot(&self) -> Option<&Option<wither::bson::Document>> {
+                    Some(&self.#ident)
+                }
+
+                /// A mutable reference to the snapshot of this instance's serialized form.
+                fn snapshot_mut(&mut self) -> Option<&mut Option<wither::bson::Document>> {
+                    Some(&mut self.#ident)
+                }
+            },
+            None => quote!(),
+        };
+        let read_only = match self.read_only {
+            Some(_) => quote! {
+                /// Whether this model is read-only.
+                fn read_only() -> bool {
+                    true
+                }
+            },
+            None => quote!(),
+        };
+        let validator = match &self.validator {
+            Some(validator) => {
+                let level = self.validation_level.as_ref().map(|level| {
+                    let variant = syn::Ident::new(&to_pascal_case(&level.value()), level.span());
+                    quote! {
+                        /// The validation level to apply along with this model's validator.
+                        fn validation_level() -> Option<wither::mongodb::options::ValidationLevel> {
+                            Some(wither::mongodb::options::ValidationLevel::#variant)
+                        }
+                    }
+                });
+                let action = self.validation_action.as_ref().map(|action| {
+                    let variant = syn::Ident::new(&to_pascal_case(&action.value()), action.span());
+                    quote! {
+                        /// The validation action to apply along with this model's validator.
+                        fn validation_action() -> Option<wither::mongodb::options::ValidationAction> {
+                            Some(wither::mongodb::options::ValidationAction::#variant)
+                        }
+                    }
+                });
+                quote! {
+                    /// The server-side validator of this model's collection.
+                    fn validator() -> Option<wither::bson::Document> {
+                        Some(#validator)
+                    }
+
+                    #level
+
+                    #action
+                }
+            }
+            None => quote!(),
+        };
+        let capped = match &self.capped {
+            Some(RawCapped { size, max }) => {
+                let max = match max {
+                    Some(max) => quote!(Some(#max)),
+                    None => quote!(None),
+                };
+                quote! {
+                    /// The parameters of this model's capped collection.
+                    fn capped() -> Option<wither::CappedOptions> {
+                        Some(wither::CappedOptions::new(#size, #max))
+                    }
+                }
+            }
+            None => quote!(),
+        };
+        let id_field = match &self.id_field {
+            Some(segments) => quote!(#(#segments).*),
+            None => quote!(id),
+        };
         quote! {
+            #(#warnings)*
+
+            #field_consts
+
+            #constructor
+
+            impl ::std::convert::TryFrom<&#name> for wither::bson::Document {
+                type Error = wither::WitherError;
+
+                /// Attempt to serialize the given model instance into a bson document.
+                fn try_from(model: &#name) -> ::std::result::Result<Self, Self::Error> {
+                    <#name as wither::Model>::document_from_instance(model)
+                }
+            }
+
+            impl ::std::convert::TryFrom<wither::bson::Document> for #name {
+                type Error = wither::WitherError;
+
+                /// Attempt to deserialize the given bson document into an instance of this model.
+                fn try_from(document: wither::bson::Document) -> ::std::result::Result<Self, Self::Error> {
+                    <#name as wither::Model>::instance_from_document(document)
+                }
+            }
+
             #[wither::async_trait]
             impl wither::Model for #name {
                 const COLLECTION_NAME: &'static str = #collection_name;
 
                 /// Get a cloned copy of this instance's ID.
                 fn id(&self) -> ::std::option::Option<wither::bson::oid::ObjectId> {
-                    self.id.clone()
+                    self.#id_field.clone()
                 }
 
                 /// Set this instance's ID.
                 fn set_id(&mut self, oid: wither::bson::oid::ObjectId) {
-                    self.id = Some(oid);
+                    self.#id_field = Some(oid);
                 }
 
                 /// The model's read concern.
@@ -111,9 +342,126 @@ impl<'a> MetaModel<'a> {
                     #selection_criteria
                 }
 
+                #default_hint
+
+                #default_max_time
+
+                #default_allow_disk_use
+
+                /// Whether `save` & `update` should force journaled write concern.
+                fn save_forces_journal() -> bool {
+                    #save_force_journal
+                }
+
+                #version_field
+
+                #snapshot_field
+
+                #read_only
+
+                #capped
+
+                #validator
+
+                #no_overwrite_fields
+
                 /// All indexes currently on this model.
                 fn indexes() -> Vec<wither::IndexModel> {
-                    vec![#(#indexes),*]
+                    #indexes
+                }
+            }
+        }
+    }
+
+    /// Expand the model's field name constants, if enabled.
+    fn expand_field_consts(&self) -> proc_macro2::TokenStream {
+        if self.field_consts.is_none() {
+            return quote!();
+        }
+        let name = self.ident;
+        let field_names = self
+            .serde_field_names()
+            .unwrap_or_else(|| abort!(self.ident, "field_consts does not support this model's serde `rename_all` rule"));
+        let consts = field_names.iter().map(|(ident, field_name)| {
+            let const_ident = quote::format_ident!("FIELD_{}", ident.to_string().trim_start_matches("r#").to_ascii_uppercase());
+            let doc = format!("The serialized name of the `{}` field.", field_name);
+            quote! {
+                #[doc = #doc]
+                pub const #const_ident: &'static str = #field_name;
+            }
+        });
+        quote! {
+            impl #name {
+                #(#consts)*
+            }
+        }
+    }
+
+    /// Expand the model's `no_overwrite_fields` method, if any fields are marked `no_overwrite`.
+    fn expand_no_overwrite_fields(&self) -> proc_macro2::TokenStream {
+        let marked: Vec<_> = self
+            .fields
+            .iter()
+            .filter(|field| field.no_overwrite)
+            .filter_map(|field| field.field.ident.as_ref())
+            .collect();
+        if marked.is_empty() {
+            return quote!();
+        }
+        let field_names = self
+            .serde_field_names()
+            .unwrap_or_else(|| abort!(marked[0], "no_overwrite does not support this model's serde `rename_all` rule"));
+        let names = marked.iter().map(|ident| {
+            let name = field_names
+                .iter()
+                .find(|(field_ident, _)| field_ident == ident)
+                .map(|(_, field_name)| field_name)
+                .unwrap_or_else(|| abort!(ident, "a `no_overwrite` field must not be skipped by serde"));
+            if name == "_id" {
+                abort!(ident, "the ID field can not be marked `no_overwrite`");
+            }
+            name
+        });
+        quote! {
+            /// The serialized names of the fields which `save` must not overwrite.
+            fn no_overwrite_fields() -> &'static [&'static str] {
+                &[#(#names),*]
+            }
+        }
+    }
+
+    /// Expand the model's `new` constructor, if enabled.
+    fn expand_constructor(&self) -> proc_macro2::TokenStream {
+        let args = match &self.constructor {
+            Some(args) => args,
+            None => return quote!(),
+        };
+        let name = self.ident;
+        let params = args.iter().map(|arg| {
+            let ty = self
+                .fields
+                .iter()
+                .find(|field| field.field.ident.as_ref() == Some(arg))
+                .map(|field| &field.field.ty);
+            quote!(#arg: #ty)
+        });
+        let inits = self
+            .fields
+            .iter()
+            .filter_map(|field| field.field.ident.as_ref())
+            .map(|ident| {
+                if args.contains(ident) {
+                    quote!(#ident)
+                } else {
+                    quote!(#ident: ::std::default::Default::default())
+                }
+            });
+        quote! {
+            impl #name {
+                /// Construct a new instance from the given fields, with all other fields set to
+                /// their default values.
+                pub fn new(#(#params),*) -> Self {
+                    Self { #(#inits),* }
                 }
             }
         }
@@ -180,11 +528,28 @@ impl<'a> MetaModel<'a> {
                 .unwrap_or_else(|| abort!(attr_meta, "malformed wither model attribute, please review the wither docs"));
             let ident_str = ident.to_string();
             match ident_str.as_str() {
+                "allow_disk_use" => self.extract_allow_disk_use(&attr_meta),
+                "capped" => self.extract_capped(&attr_meta),
                 "collection_name" => self.extract_collection_name(&attr_meta),
+                "field_consts" => self.extract_field_consts(&attr_meta),
+                "hint" => self.extract_hint(&attr_meta),
+                "id_field" => self.extract_id_field(&attr_meta),
                 "index" => self.extract_index(&attr_meta),
+                "indexes_from" => self.extract_indexes_from(&attr_meta),
+                "max_time_ms" => self.extract_max_time_ms(&attr_meta),
+                "new" => self.extract_constructor(&attr_meta),
                 "read_concern" => self.extract_read_concern(&attr_meta),
+                "read_only" => self.extract_read_only(&attr_meta),
+                "read_preference" => self.extract_read_preference(&attr_meta),
+                "save_force_journal" => self.extract_save_force_journal(&attr_meta),
                 "selection_criteria" => self.extract_selection_criteria(&attr_meta),
                 "skip_serde_checks" => self.extract_skip_serde_checks(&attr_meta),
+                "snapshot_field" => self.extract_snapshot_field(&attr_meta),
+                "text_index" => self.extract_text_index(&attr_meta),
+                "validation_action" => self.extract_validation_action(&attr_meta),
+                "validation_level" => self.extract_validation_level(&attr_meta),
+                "validator" => self.extract_validator(&attr_meta),
+                "version_field" => self.extract_version_field(&attr_meta),
                 "write_concern" => self.extract_write_concern(&attr_meta),
                 _ => abort!(ident, "unrecognized wither model attribute"),
             }
@@ -209,15 +574,183 @@ impl<'a> MetaModel<'a> {
         self.collection_name = Some(name);
     }
 
+    /// Extract the field consts attribute from the given meta.
+    fn extract_field_consts(&mut self, meta: &syn::Meta) {
+        match meta {
+            syn::Meta::Path(path) if path.is_ident("field_consts") => (),
+            _ => abort!(meta, "this attribute must be specified simply as `#[model(field_consts)]`"),
+        }
+        if self.field_consts.is_some() {
+            abort!(meta, DUPLICATE_ATTR_SPEC);
+        }
+        self.field_consts = Some(());
+    }
+
+    /// Extract the hint attribute from the given meta.
+    ///
+    /// The hint may be given as the name of an index, or as a `doc!{...}` of index keys.
+    fn extract_hint(&mut self, meta: &syn::Meta) {
+        let lit = match meta {
+            syn::Meta::NameValue(val) => match &val.lit {
+                syn::Lit::Str(inner) => inner,
+                lit => abort!(lit, "this must be a string literal"),
+            },
+            _ => abort!(meta, META_MUST_BE_KV_PAIR),
+        };
+        let value = lit.value();
+        if value.is_empty() {
+            abort!(lit, "hints must be the name of an index, or a `doc!{...}` of index keys");
+        }
+        let hint = if value.trim_start().starts_with("doc!") {
+            let keys: proc_macro2::TokenStream =
+                syn::parse_str(&value).unwrap_or_else(|err| abort!(lit, "error parsing hint keys, must be valid Rust code"; hint=err));
+            quote!(wither::mongodb::options::Hint::Keys(#keys))
+        } else {
+            quote!(wither::mongodb::options::Hint::Name(#value.to_string()))
+        };
+        if self.hint.is_some() {
+            abort!(meta, DUPLICATE_ATTR_SPEC);
+        }
+        self.hint = Some(hint);
+    }
+
+    /// Extract the ID field attribute from the given meta.
+    fn extract_id_field(&mut self, meta: &syn::Meta) {
+        let lit = match meta {
+            syn::Meta::NameValue(val) => match &val.lit {
+                syn::Lit::Str(inner) => inner,
+                lit => abort!(lit, "this must be a string literal"),
+            },
+            _ => abort!(meta, META_MUST_BE_KV_PAIR),
+        };
+        let segments = lit
+            .value()
+            .split('.')
+            .map(|segment| match syn::parse_str::<syn::Ident>(segment) {
+                Ok(mut ident) => {
+                    ident.set_span(lit.span());
+                    ident
+                }
+                Err(_) => abort!(lit, "this must be the name of a field, or a dotted path to a nested field"),
+            })
+            .collect();
+        if self.id_field.is_some() {
+            abort!(meta, DUPLICATE_ATTR_SPEC);
+        }
+        self.id_field = Some(segments);
+    }
+
+    /// Extract the constructor attribute from the given meta.
+    fn extract_constructor(&mut self, meta: &syn::Meta) {
+        const CONSTRUCTOR_FORM: &str = "this attribute must be specified as a list of field names, eg: `#[model(new(email, name))]`";
+        let list = match meta {
+            syn::Meta::List(list) => list,
+            _ => abort!(meta, CONSTRUCTOR_FORM),
+        };
+        let mut args: Vec<syn::Ident> = vec![];
+        for nested in &list.nested {
+            let ident = match nested {
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) => path.get_ident().cloned(),
+                _ => None,
+            }
+            .unwrap_or_else(|| abort!(nested, CONSTRUCTOR_FORM));
+            if args.contains(&ident) {
+                abort!(ident, "field `{}` is listed more than once", ident);
+            }
+            args.push(ident);
+        }
+        if self.constructor.is_some() {
+            abort!(meta, DUPLICATE_ATTR_SPEC);
+        }
+        self.constructor = Some(args);
+    }
+
+    /// Extract the version field attribute from the given meta.
+    fn extract_version_field(&mut self, meta: &syn::Meta) {
+        let ident = Self::parse_field_ident(meta);
+        if self.version_field.is_some() {
+            abort!(meta, DUPLICATE_ATTR_SPEC);
+        }
+        self.version_field = Some(ident);
+    }
+
+    /// Extract the snapshot field attribute from the given meta.
+    fn extract_snapshot_field(&mut self, meta: &syn::Meta) {
+        let ident = Self::parse_field_ident(meta);
+        if self.snapshot_field.is_some() {
+            abort!(meta, DUPLICATE_ATTR_SPEC);
+        }
+        self.snapshot_field = Some(ident);
+    }
+
+    /// Parse the name of a field given as the string literal value of the given meta.
+    fn parse_field_ident(meta: &syn::Meta) -> syn::Ident {
+        let lit = match meta {
+            syn::Meta::NameValue(val) => match &val.lit {
+                syn::Lit::Str(inner) => inner,
+                lit => abort!(lit, "this must be a string literal"),
+            },
+            _ => abort!(meta, META_MUST_BE_KV_PAIR),
+        };
+        let mut ident = syn::parse_str::<syn::Ident>(&lit.value()).unwrap_or_else(|_| abort!(lit, "this must be the name of a field"));
+        ident.set_span(lit.span());
+        ident
+    }
+
     /// Extract an index attribute from the given meta.
     fn extract_index(&mut self, meta: &syn::Meta) {
         let idx = match RawIndexModel::from_meta(meta) {
             Ok(idx) => idx,
             Err(err) => abort!(meta, "malformed wither model index specification"; hint=err),
         };
+        if idx.keys.is_none() && idx.keys_fn.is_none() {
+            abort!(meta, "index specifications must include one of `keys` or `keys_fn`");
+        }
+        self.indexes.push(IndexModelTokens::from(idx));
+    }
+
+    /// Extract an indexes from attribute from the given meta.
+    fn extract_indexes_from(&mut self, meta: &syn::Meta) {
+        let fnpath = match meta {
+            syn::Meta::NameValue(val) => match syn::Path::from_value(&val.lit) {
+                Ok(path) => path,
+                Err(err) => abort!(val, "this must be a string literal"; hint=err),
+            },
+            _ => abort!(meta, META_MUST_BE_KV_PAIR),
+        };
+        self.indexes_from.push(fnpath);
+    }
+
+    /// Extract a text index attribute from the given meta.
+    fn extract_text_index(&mut self, meta: &syn::Meta) {
+        let idx = match RawTextIndex::from_meta(meta) {
+            Ok(idx) => idx,
+            Err(err) => abort!(meta, "malformed wither model text index specification"; hint=err),
+        };
+        if self.has_text_index {
+            abort!(meta, "only one text index may be declared per model");
+        }
+        self.has_text_index = true;
         self.indexes.push(IndexModelTokens::from(idx));
     }
 
+    /// Extract the max time attribute from the given meta.
+    fn extract_max_time_ms(&mut self, meta: &syn::Meta) {
+        let val = match meta {
+            syn::Meta::NameValue(val) => match &val.lit {
+                syn::Lit::Int(inner) => inner
+                    .base10_parse::<u64>()
+                    .unwrap_or_else(|err| abort!(inner, "this must be a positive integer"; hint=err)),
+                lit => abort!(lit, "this must be an integer literal"),
+            },
+            _ => abort!(meta, META_MUST_BE_KV_PAIR),
+        };
+        if self.max_time_ms.is_some() {
+            abort!(meta, DUPLICATE_ATTR_SPEC);
+        }
+        self.max_time_ms = Some(val);
+    }
+
     /// Extract the read concern attribute from the given meta.
     fn extract_read_concern(&mut self, meta: &syn::Meta) {
         let rc = match ReadConcern::from_meta(meta) {
@@ -230,6 +763,95 @@ impl<'a> MetaModel<'a> {
         self.read_concern = Some(rc);
     }
 
+    /// Extract the allow disk use attribute from the given meta.
+    fn extract_allow_disk_use(&mut self, meta: &syn::Meta) {
+        match meta {
+            syn::Meta::Path(path) if path.is_ident("allow_disk_use") => (),
+            _ => abort!(meta, "this attribute must be specified simply as `#[model(allow_disk_use)]`"),
+        }
+        if self.allow_disk_use.is_some() {
+            abort!(meta, DUPLICATE_ATTR_SPEC);
+        }
+        self.allow_disk_use = Some(());
+    }
+
+    /// Extract the read only attribute from the given meta.
+    fn extract_read_only(&mut self, meta: &syn::Meta) {
+        match meta {
+            syn::Meta::Path(path) if path.is_ident("read_only") => (),
+            _ => abort!(meta, "this attribute must be specified simply as `#[model(read_only)]`"),
+        }
+        if self.read_only.is_some() {
+            abort!(meta, DUPLICATE_ATTR_SPEC);
+        }
+        self.read_only = Some(());
+    }
+
+    /// Extract the collection validator attribute from the given meta.
+    fn extract_validator(&mut self, meta: &syn::Meta) {
+        let lit = match meta {
+            syn::Meta::NameValue(val) => match &val.lit {
+                syn::Lit::Str(inner) => inner,
+                lit => abort!(lit, "this must be a string literal"),
+            },
+            _ => abort!(meta, META_MUST_BE_KV_PAIR),
+        };
+        let validator: proc_macro2::TokenStream =
+            syn::parse_str(&lit.value()).unwrap_or_else(|err| abort!(lit, "error parsing validator, must be valid Rust code"; hint=err));
+        if self.validator.is_some() {
+            abort!(meta, DUPLICATE_ATTR_SPEC);
+        }
+        self.validator = Some(validator);
+    }
+
+    /// Extract the validation level attribute from the given meta.
+    fn extract_validation_level(&mut self, meta: &syn::Meta) {
+        let lit = parse_validation_option(meta, &["off", "strict", "moderate"]);
+        if self.validation_level.is_some() {
+            abort!(meta, DUPLICATE_ATTR_SPEC);
+        }
+        self.validation_level = Some(lit);
+    }
+
+    /// Extract the validation action attribute from the given meta.
+    fn extract_validation_action(&mut self, meta: &syn::Meta) {
+        let lit = parse_validation_option(meta, &["error", "warn"]);
+        if self.validation_action.is_some() {
+            abort!(meta, DUPLICATE_ATTR_SPEC);
+        }
+        self.validation_action = Some(lit);
+    }
+
+    /// Extract the capped collection attribute from the given meta.
+    fn extract_capped(&mut self, meta: &syn::Meta) {
+        let capped = match RawCapped::from_meta(meta) {
+            Ok(capped) => capped,
+            Err(err) => abort!(meta, "malformed wither model capped specification"; hint=err),
+        };
+        if capped.size == 0 {
+            abort!(meta, "the size of a capped collection must be greater than zero");
+        }
+        if self.capped.is_some() {
+            abort!(meta, DUPLICATE_ATTR_SPEC);
+        }
+        self.capped = Some(capped);
+    }
+
+    /// Extract the save force journal attribute from the given meta.
+    fn extract_save_force_journal(&mut self, meta: &syn::Meta) {
+        let val = match meta {
+            syn::Meta::NameValue(val) => match &val.lit {
+                syn::Lit::Bool(inner) => inner.value,
+                lit => abort!(lit, "this must be a boolean literal"),
+            },
+            _ => abort!(meta, META_MUST_BE_KV_PAIR),
+        };
+        if self.save_force_journal.is_some() {
+            abort!(meta, DUPLICATE_ATTR_SPEC);
+        }
+        self.save_force_journal = Some(val);
+    }
+
     /// Extract the selection criteria attribute from the given meta.
     fn extract_selection_criteria(&mut self, meta: &syn::Meta) {
         let fnpath = match meta {
@@ -240,9 +862,22 @@ impl<'a> MetaModel<'a> {
             _ => abort!(meta, META_MUST_BE_KV_PAIR),
         };
         if self.selection_criteria.is_some() {
-            abort!(meta, DUPLICATE_ATTR_SPEC);
+            abort!(meta, DUPLICATE_SELECTION_CRITERIA_SPEC);
+        }
+        self.selection_criteria = Some(SelectionCriteria::Function(fnpath));
+    }
+
+    /// Extract the read preference attribute from the given meta.
+    fn extract_read_preference(&mut self, meta: &syn::Meta) {
+        let rp = match RawReadPreference::from_meta(meta) {
+            Ok(rp) => rp,
+            Err(err) => abort!(meta, "malformed wither model read preference attribute"; hint=err),
+        };
+        let rp = ReadPreference::from(rp);
+        if self.selection_criteria.is_some() {
+            abort!(meta, DUPLICATE_SELECTION_CRITERIA_SPEC);
         }
-        self.selection_criteria = Some(fnpath);
+        self.selection_criteria = Some(SelectionCriteria::ReadPreference(Box::new(rp)));
     }
 
     /// Extract the skip serde checks attribute from the given meta.
@@ -275,7 +910,19 @@ impl<'a> MetaModel<'a> {
             // Build an IR of the fields which holds the original field object & its filtered attrs.
             .map(|field| {
                 let serde_attrs = Self::parse_attrs(&field.attrs, "serde");
-                FieldWithFilteredAttrs{serde_attrs, field}
+                let mut no_overwrite = false;
+                for attr_meta in Self::parse_attrs(&field.attrs, MODEL_HELPER_ATTR) {
+                    match &attr_meta {
+                        syn::Meta::Path(path) if path.is_ident("no_overwrite") => {
+                            if no_overwrite {
+                                abort!(attr_meta, DUPLICATE_ATTR_SPEC);
+                            }
+                            no_overwrite = true;
+                        }
+                        _ => abort!(attr_meta, "unrecognized wither model field attribute"),
+                    }
+                }
+                FieldWithFilteredAttrs{serde_attrs, no_overwrite, field}
             })
             .collect();
     }
@@ -318,9 +965,15 @@ impl<'a> MetaModel<'a> {
 
     /// Ensure the model has an ID field which is structured as needed.
     ///
-    /// NB: the type of the ID field is not checked here. The compiler still checks that the type
-    /// matches as needed when the AST is written back out to the compiler.
+    /// Unless serde checks are skipped, the ID field's type is checked before its serde attrs, so
+    /// that a non-`Option` ID field is reported as such, rather than via its serde attrs.
     fn check_id_field(&self) {
+        if self.skip_serde_checks.is_none() {
+            self.check_duplicate_id_fields();
+        }
+        if let Some(segments) = &self.id_field {
+            return self.check_custom_id_field(segments);
+        }
         // Unpack the struct fields.
         // Look for the model's ID field.
         let id_field = self
@@ -331,12 +984,300 @@ impl<'a> MetaModel<'a> {
                 None => false,
             })
             .unwrap_or_else(|| abort!(self.ident, "wither models must have a field `id` of type `Option<bson::oid::ObjectId>`"));
-        // Ensure the ID field has needed serde attributes, unless this check is disabled.
+        // Ensure the ID field has needed type & serde attributes, unless these checks are disabled.
         if self.skip_serde_checks.is_none() {
+            Self::check_id_type(id_field);
             self.check_id_serde_attrs(id_field);
         }
     }
 
+    /// Ensure no more than one of the model's fields serializes to `_id`.
+    fn check_duplicate_id_fields(&self) {
+        let field_names = match self.serde_field_names() {
+            Some(field_names) => field_names,
+            None => return,
+        };
+        if let Some((ident, _)) = field_names.iter().filter(|(_, name)| name == "_id").nth(1) {
+            abort!(ident, "multiple fields of this model serialize to `_id`"; help = "only the model's ID field may be serialized as `_id`");
+        }
+    }
+
+    /// Ensure each index path, or the first segment of each nested index path, corresponds to a
+    /// field of the model, emitting a warning for each path which does not.
+    fn check_index_paths(&mut self) {
+        // Flattened fields may contribute any key, so nothing can be checked.
+        if self
+            .fields
+            .iter()
+            .any(|field| field.serde_attrs.iter().any(|attr| attr.path().is_ident("flatten")))
+        {
+            return;
+        }
+        let field_names = match self.serde_field_names() {
+            Some(field_names) => field_names,
+            None => return,
+        };
+        let mut warnings = vec![];
+        for index in &self.indexes {
+            for path in index.key_paths() {
+                let head = path.split('.').next().unwrap_or_default();
+                if head.starts_with('$') || field_names.iter().any(|(_, name)| name == head) {
+                    continue;
+                }
+                let note = format!(
+                    "index path `{}` does not begin with a known field of this model; use `#[model(skip_serde_checks)]` if this is intended",
+                    path
+                );
+                warnings.push(quote_spanned! {index.span=>
+                    const _: () = {
+                        #[deprecated(note = #note)]
+                        #[allow(non_upper_case_globals)]
+                        const wither_unknown_index_path: () = ();
+                        wither_unknown_index_path
+                    };
+                });
+            }
+        }
+        self.warnings.extend(warnings);
+    }
+
+    /// Get the serialized names of this model's fields, paired with their idents, accounting for
+    /// serde renames. Fields marked with `#[serde(skip)]` are omitted.
+    ///
+    /// Returns `None` if the model uses a `rename_all` rule which is not understood.
+    fn serde_field_names(&self) -> Option<Vec<(&'a syn::Ident, String)>> {
+        let rename_all = Self::parse_attrs(self.attrs, "serde")
+            .iter()
+            .find_map(|attr| serde_serialized_name(attr, "rename_all"));
+        let mut names = vec![];
+        for field in &self.fields {
+            if field.serde_attrs.iter().any(|attr| attr.path().is_ident("skip")) {
+                continue;
+            }
+            let rename = field
+                .serde_attrs
+                .iter()
+                .find_map(|attr| serde_serialized_name(attr, "rename"));
+            let field_ident = match &field.field.ident {
+                Some(ident) => ident,
+                None => continue,
+            };
+            let ident = field_ident.to_string().trim_start_matches("r#").to_string();
+            let name = match (rename, rename_all.as_deref()) {
+                (Some(rename), _) => rename,
+                (None, None) => ident,
+                (None, Some(rule)) => apply_serde_rename_rule(rule, &ident)?,
+            };
+            names.push((field_ident, name));
+        }
+        Some(names)
+    }
+
+    /// Ensure each literal index key value is a valid direction or index type, such as `1`, `-1`
+    /// or `"2dsphere"`. Values which are not literals are not checked.
+    fn check_index_directions(&self) {
+        const INDEX_TYPES: [&str; 5] = ["text", "2d", "2dsphere", "geoHaystack", "hashed"];
+        use proc_macro2::TokenTree;
+        for index in &self.indexes {
+            for (key, value) in index.key_entries() {
+                let lit = match value.as_slice() {
+                    [TokenTree::Literal(lit)] => lit.clone(),
+                    [TokenTree::Punct(punct), TokenTree::Literal(lit)] if punct.as_char() == '-' => lit.clone(),
+                    _ => continue,
+                };
+                let valid = match syn::parse2::<syn::Lit>(TokenTree::Literal(lit).into()) {
+                    Ok(syn::Lit::Int(int)) => int.base10_digits() == "1",
+                    Ok(syn::Lit::Str(lit)) => value.len() == 1 && INDEX_TYPES.contains(&lit.value().as_str()),
+                    _ => continue,
+                };
+                if !valid {
+                    let value: proc_macro2::TokenStream = value.into_iter().collect();
+                    abort!(
+                        index.span,
+                        "invalid value `{}` for index key `{}`", value, key;
+                        help = "index keys must be `1`, `-1`, or one of {:?}", INDEX_TYPES
+                    );
+                }
+            }
+        }
+    }
+
+    /// Ensure at most one of the model's indexes, including any declared via `text_index`, has a
+    /// `"text"` key, as MongoDB allows only one text index per collection.
+    fn check_text_indexes(&self) {
+        let is_text = |value: &[proc_macro2::TokenTree]| match value {
+            [proc_macro2::TokenTree::Literal(lit)] => {
+                matches!(syn::parse2::<syn::LitStr>(proc_macro2::TokenTree::Literal(lit.clone()).into()), Ok(lit) if lit.value() == "text")
+            }
+            _ => false,
+        };
+        let mut text_indexes = self
+            .indexes
+            .iter()
+            .filter(|index| index.key_entries().iter().any(|(_, value)| is_text(value)));
+        if let (Some(first), Some(second)) = (text_indexes.next(), text_indexes.next()) {
+            let first_fields: Vec<_> = first
+                .key_entries()
+                .into_iter()
+                .filter(|(_, value)| is_text(value))
+                .map(|(key, _)| format!("`{}`", key))
+                .collect();
+            abort!(
+                second.span,
+                "only one text index may be declared per model";
+                note = "a text index over {} is already declared", first_fields.join(", ");
+                help = "MongoDB allows only one text index per collection; combine the fields into a single text index"
+            );
+        }
+    }
+
+    /// Ensure the options of each index may be combined, aborting on combinations which MongoDB
+    /// rejects, and emitting a warning for `sparse` unique indexes, which are often mistaken for
+    /// partial unique indexes.
+    fn check_index_options(&mut self) {
+        let is_true = |value: &[proc_macro2::TokenTree]| matches!(value, [proc_macro2::TokenTree::Ident(ident)] if ident == "true");
+        let mut warnings = vec![];
+        for index in &self.indexes {
+            let options = index.option_entries();
+            let option = |name: &str| {
+                options
+                    .iter()
+                    .find(|(key, _)| key == name)
+                    .map(|(_, value)| value.as_slice())
+            };
+            let sparse = option("sparse").map(is_true).unwrap_or(false);
+            if sparse && option("partialFilterExpression").is_some() {
+                abort!(
+                    index.span,
+                    "an index may not specify both `sparse` and `partialFilterExpression`";
+                    help = "remove `sparse`, and express the documents to index via `partialFilterExpression` alone"
+                );
+            }
+            if sparse && option("unique").map(is_true).unwrap_or(false) {
+                let note =
+                    "a `sparse` unique index only skips documents missing all of the indexed fields; consider a `partialFilterExpression` instead";
+                warnings.push(quote_spanned! {index.span=>
+                    const _: () = {
+                        #[deprecated(note = #note)]
+                        #[allow(non_upper_case_globals)]
+                        const wither_sparse_unique_index: () = ();
+                        wither_sparse_unique_index
+                    };
+                });
+            }
+        }
+        self.warnings.extend(warnings);
+    }
+
+    /// Ensure each field given via `#[model(new(...))]` is present on the model.
+    fn check_constructor_fields(&self) {
+        for arg in self.constructor.iter().flatten() {
+            if !self.fields.iter().any(|field| field.field.ident.as_ref() == Some(arg)) {
+                abort!(arg, "no field named `{}` was found on this model", arg);
+            }
+        }
+    }
+
+    /// Ensure the field given via `#[model(version_field=...)]` is present on the model.
+    fn check_version_field(&self) {
+        if let Some(ident) = &self.version_field {
+            if !self.fields.iter().any(|field| field.field.ident.as_ref() == Some(ident)) {
+                abort!(ident, "no field named `{}` was found on this model", ident);
+            }
+        }
+    }
+
+    /// Ensure the field given via `#[model(snapshot_field=...)]` is present on the model, and is
+    /// skipped by serde, so that the snapshot is never part of the serialized form.
+    fn check_snapshot_field(&self) {
+        if let Some(ident) = &self.snapshot_field {
+            let field = self
+                .fields
+                .iter()
+                .find(|field| field.field.ident.as_ref() == Some(ident))
+                .unwrap_or_else(|| abort!(ident, "no field named `{}` was found on this model", ident));
+            if !field.serde_attrs.iter().any(|attr| attr.path().is_ident("skip")) {
+                abort!(field.field.ident, "the snapshot field must be marked with `#[serde(skip)]`");
+            }
+        }
+    }
+
+    /// Get the serialized name of the given version field, accounting for serde renames.
+    fn version_field_name(&self, ident: &syn::Ident) -> String {
+        let field_names = self
+            .serde_field_names()
+            .unwrap_or_else(|| abort!(ident, "version_field does not support this model's serde `rename_all` rule"));
+        field_names
+            .into_iter()
+            .find(|(field_ident, _)| *field_ident == ident)
+            .map(|(_, field_name)| field_name)
+            .unwrap_or_else(|| abort!(ident, "the version field must not be skipped by serde"))
+    }
+
+    /// Ensure the ID field given via `#[model(id_field=...)]` is present on the model.
+    ///
+    /// When the path points to a field nested within another field, such as a flattened struct,
+    /// only the outermost field can be checked here. Serde attrs are checked only for ID fields
+    /// declared directly on the model.
+    fn check_custom_id_field(&self, segments: &[syn::Ident]) {
+        let head = &segments[0];
+        let field = self
+            .fields
+            .iter()
+            .find(|field| field.field.ident.as_ref() == Some(head))
+            .unwrap_or_else(|| abort!(head, "no field named `{}` was found on this model", head));
+        if segments.len() == 1 && self.skip_serde_checks.is_none() {
+            Self::check_id_type(field);
+            self.check_id_serde_attrs(field);
+        }
+    }
+
+    /// Ensure a validation level or action is only declared along with a validator.
+    fn check_validation_options(&self) {
+        if self.validator.is_some() {
+            return;
+        }
+        if let Some(lit) = self.validation_level.as_ref().or(self.validation_action.as_ref()) {
+            abort!(lit, "validation options require a validator"; help = "declare the model's validator via the `validator` attribute");
+        }
+    }
+
+    /// Ensure the given ID field is of type `Option<ObjectId>`.
+    ///
+    /// The compiler would reject other types as well, but only with an error pointing into the
+    /// generated code. Types are matched by their last path segment, so `ObjectId` may be given
+    /// by any path; type aliases can not be resolved, and are rejected.
+    fn check_id_type(id_field: &FieldWithFilteredAttrs<'a>) {
+        let last_segment = |ty: &syn::Type| match ty {
+            syn::Type::Path(path) if path.qself.is_none() => path.path.segments.last().cloned(),
+            _ => None,
+        };
+        let segment = last_segment(&id_field.field.ty);
+        let inner = match segment
+            .as_ref()
+            .map(|segment| (segment.ident.to_string(), &segment.arguments))
+        {
+            Some((ident, syn::PathArguments::AngleBracketed(args))) if ident == "Option" && args.args.len() == 1 => match &args.args[0] {
+                syn::GenericArgument::Type(inner) => Some(inner),
+                _ => None,
+            },
+            _ => None,
+        };
+        if inner
+            .and_then(last_segment)
+            .is_some_and(|segment| segment.ident == "ObjectId")
+        {
+            return;
+        }
+        let ty = &id_field.field.ty;
+        let found = quote!(#ty).to_string().replace(' ', "");
+        let help = match segment {
+            Some(segment) if segment.ident == "ObjectId" => format!("wrap the type in an `Option`, EG `Option<{}>`", found),
+            _ => "wither assigns IDs as `ObjectId`s; if this type is an alias of `Option<ObjectId>`, add `#[model(skip_serde_checks)]`".to_string(),
+        };
+        abort!(ty, "wither model ID fields must be of type `Option<bson::oid::ObjectId>`, found `{}`", found; help = help);
+    }
+
     // Ensure the `id` field has required serde attrs.
     fn check_id_serde_attrs(&self, id_field: &FieldWithFilteredAttrs<'a>) {
         let mut found_rename = false;
@@ -378,6 +1319,8 @@ impl<'a> MetaModel<'a> {
 pub struct FieldWithFilteredAttrs<'a> {
     /// All collected serde attributes.
     serde_attrs: Vec<syn::Meta>,
+    /// Whether the field is marked with `#[model(no_overwrite)]`.
+    no_overwrite: bool,
     /// The original field.
     field: &'a syn::Field,
 }
@@ -473,14 +1416,108 @@ impl quote::ToTokens for OptionWriteConcern<'_> {
 //////////////////////////////////////////////////////////////////////////////
 // SelectionCriteria /////////////////////////////////////////////////////////
 
-pub struct OptionSelectionCriteria<'a>(&'a Option<syn::Path>);
+/// The ways in which a model's selection criteria may be declared.
+pub enum SelectionCriteria {
+    /// A path to a function producing the selection criteria.
+    Function(syn::Path),
+    /// A declarative read preference.
+    ReadPreference(Box<ReadPreference>),
+}
+
+/// A type wrapper around the mode of a `mongodb::options::ReadPreference`.
+#[derive(FromMeta)]
+pub enum ReadPreferenceMode {
+    Primary,
+    Secondary,
+    PrimaryPreferred,
+    SecondaryPreferred,
+    Nearest,
+}
+
+/// The raw model used for deriving a read preference on models.
+#[derive(FromMeta)]
+pub struct RawReadPreference {
+    /// The read preference mode.
+    pub mode: ReadPreferenceMode,
+    /// The maximum replication lag of eligible secondaries, in seconds.
+    #[darling(default)]
+    pub max_staleness_secs: Option<darling::util::SpannedValue<u64>>,
+    /// The document to use as the tag set of eligible members.
+    #[darling(default)]
+    pub tags: Option<darling::util::SpannedValue<String>>,
+}
+
+/// A validated read preference, along with its parsed tag set.
+pub struct ReadPreference {
+    mode: ReadPreferenceMode,
+    max_staleness_secs: Option<u64>,
+    tags: Option<syn::Expr>,
+}
+
+impl From<RawReadPreference> for ReadPreference {
+    fn from(src: RawReadPreference) -> Self {
+        if let ReadPreferenceMode::Primary = src.mode {
+            if let Some(span) = src
+                .tags
+                .as_ref()
+                .map(|tags| tags.span())
+                .or_else(|| src.max_staleness_secs.as_ref().map(|secs| secs.span()))
+            {
+                abort!(span, "the primary read preference does not support tags or max staleness"; help = "use a mode other than `primary`, EG `primary_preferred`");
+            }
+        }
+        if let Some(secs) = &src.max_staleness_secs {
+            if **secs < 90 {
+                abort!(secs.span(), "max_staleness_secs must be at least 90 seconds");
+            }
+        }
+        let tags = src
+            .tags
+            .map(|tags| syn::parse_str(&tags).unwrap_or_else(|err| abort!(tags.span(), "error parsing tags, must be valid Rust code"; hint=err)));
+        Self {
+            mode: src.mode,
+            max_staleness_secs: src.max_staleness_secs.map(|secs| *secs),
+            tags,
+        }
+    }
+}
+
+pub struct OptionSelectionCriteria<'a>(&'a Option<SelectionCriteria>);
 
 impl quote::ToTokens for OptionSelectionCriteria<'_> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        match self.0 {
-            None => tokens.extend(quote!(None)),
-            Some(path) => tokens.extend(quote!(Some(#path()))),
-        }
+        let rp = match self.0 {
+            None => return tokens.extend(quote!(None)),
+            Some(SelectionCriteria::Function(path)) => return tokens.extend(quote!(Some(#path()))),
+            Some(SelectionCriteria::ReadPreference(rp)) => rp,
+        };
+        let tag_sets = match &rp.tags {
+            Some(tags) => quote! {
+                Some(vec![{
+                    let tags: wither::bson::Document = #tags;
+                    tags.into_iter()
+                        .map(|(key, val)| match val {
+                            wither::bson::Bson::String(val) => (key, val),
+                            val => (key, val.to_string()),
+                        })
+                        .collect::<wither::mongodb::options::TagSet>()
+                }])
+            },
+            None => quote!(None),
+        };
+        let max_staleness = match &rp.max_staleness_secs {
+            Some(val) => quote!(Some(::std::time::Duration::from_secs(#val))),
+            None => quote!(None),
+        };
+        let options = quote!(wither::mongodb::options::ReadPreferenceOptions::builder().tag_sets(#tag_sets).max_staleness(#max_staleness).build());
+        let read_preference = match rp.mode {
+            ReadPreferenceMode::Primary => quote!(wither::mongodb::options::ReadPreference::Primary),
+            ReadPreferenceMode::Secondary => quote!(wither::mongodb::options::ReadPreference::Secondary { options: #options }),
+            ReadPreferenceMode::PrimaryPreferred => quote!(wither::mongodb::options::ReadPreference::PrimaryPreferred { options: #options }),
+            ReadPreferenceMode::SecondaryPreferred => quote!(wither::mongodb::options::ReadPreference::SecondaryPreferred { options: #options }),
+            ReadPreferenceMode::Nearest => quote!(wither::mongodb::options::ReadPreference::Nearest { options: #options }),
+        };
+        tokens.extend(quote!(Some(wither::mongodb::options::SelectionCriteria::ReadPreference(#read_preference))));
     }
 }
 
@@ -491,19 +1528,139 @@ impl quote::ToTokens for OptionSelectionCriteria<'_> {
 #[derive(Debug, FromMeta)]
 pub struct RawIndexModel {
     /// The document to use for the index keys.
-    pub keys: darling::util::SpannedValue<String>,
+    #[darling(default)]
+    pub keys: Option<darling::util::SpannedValue<String>>,
+    /// The path to a function producing the document to use for the index keys.
+    #[darling(default)]
+    pub keys_fn: Option<darling::util::SpannedValue<String>>,
     /// The document to use for the index options.
     #[darling(default)]
     pub options: darling::util::SpannedValue<Option<String>>,
+    /// The path to a function producing the document to use for the index options.
+    #[darling(default)]
+    pub options_fn: Option<darling::util::SpannedValue<String>>,
 }
 
 impl From<RawIndexModel> for IndexModelTokens {
     fn from(src: RawIndexModel) -> Self {
-        let keys = syn::parse_str(&src.keys).unwrap_or_else(|err| abort!(src.keys.span(), "error parsing keys, must be valid Rust code"; hint=err));
-        let options = src.options.as_ref().as_ref().map(|opts| {
-            syn::parse_str(opts.as_ref()).unwrap_or_else(|err| abort!(src.options.span(), "error parsing options, must be valid Rust code"; hint=err))
+        let (keys, span) = match (&src.keys, &src.keys_fn) {
+            (Some(keys), None) => (
+                syn::parse_str(keys).unwrap_or_else(|err| abort!(keys.span(), "error parsing keys, must be valid Rust code"; hint=err)),
+                keys.span(),
+            ),
+            (None, Some(keys_fn)) => (parse_index_fn(keys_fn), keys_fn.span()),
+            (Some(_), Some(keys_fn)) => abort!(keys_fn.span(), "only one of `keys` & `keys_fn` may be specified"),
+            (None, None) => unreachable!("index specifications are checked for keys before conversion"),
+        };
+        let options = match (src.options.as_ref(), &src.options_fn) {
+            (Some(opts), None) => Some(
+                syn::parse_str(opts).unwrap_or_else(|err| abort!(src.options.span(), "error parsing options, must be valid Rust code"; hint=err)),
+            ),
+            (None, Some(options_fn)) => Some(parse_index_fn(options_fn)),
+            (Some(_), Some(options_fn)) => abort!(options_fn.span(), "only one of `options` & `options_fn` may be specified"),
+            (None, None) => None,
+        };
+        Self { keys, options, span }
+    }
+}
+
+/// Parse the string literal of the given validation option, which must be one of the given values.
+fn parse_validation_option(meta: &syn::Meta, values: &[&str]) -> syn::LitStr {
+    let lit = match meta {
+        syn::Meta::NameValue(syn::MetaNameValue { lit: syn::Lit::Str(lit), .. }) => lit.clone(),
+        syn::Meta::NameValue(val) => abort!(val.lit, "this must be a string literal"),
+        _ => abort!(meta, META_MUST_BE_KV_PAIR),
+    };
+    if !values.contains(&lit.value().as_str()) {
+        let name = meta.path().get_ident().map(ToString::to_string).unwrap_or_default();
+        abort!(lit, "invalid value `{}` for `{}`", lit.value(), name; help = "must be one of {:?}", values);
+    }
+    lit
+}
+
+/// Convert the given lowercase value to pascal case, EG `moderate` to `Moderate`.
+fn to_pascal_case(value: &str) -> String {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// Parse the given path to a function producing an index document, into a call of the function.
+fn parse_index_fn(path: &darling::util::SpannedValue<String>) -> proc_macro2::TokenStream {
+    let fnpath = syn::parse_str::<syn::Path>(path)
+        .unwrap_or_else(|err| abort!(path.span(), "this must be the path to a function returning a `Document`"; hint=err));
+    quote!(#fnpath())
+}
+
+/// The raw model used for declaring a model's collection as capped.
+#[derive(Debug, FromMeta)]
+pub struct RawCapped {
+    /// The maximum size of the collection, in bytes.
+    pub size: u64,
+    /// The maximum number of documents in the collection.
+    #[darling(default)]
+    pub max: Option<u64>,
+}
+
+/// The raw model used for deriving text indices on models.
+#[derive(Debug, FromMeta)]
+pub struct RawTextIndex {
+    /// The fields to index, as a comma separated list of `field` or `field:weight` entries.
+    pub fields: darling::util::SpannedValue<String>,
+    /// The default language of the index.
+    #[darling(default)]
+    pub default_language: Option<String>,
+    /// The name of the field which overrides the language of a document.
+    #[darling(default)]
+    pub language_override: Option<String>,
+    /// An explicit name for the index.
+    #[darling(default)]
+    pub name: Option<String>,
+}
+
+impl From<RawTextIndex> for IndexModelTokens {
+    fn from(src: RawTextIndex) -> Self {
+        let span = src.fields.span();
+        let mut fields: Vec<(String, i32)> = vec![];
+        for entry in src.fields.split(',').map(str::trim) {
+            let (field, weight) = match entry.split_once(':') {
+                Some((field, weight)) => match weight.trim().parse::<i32>() {
+                    Ok(weight) if (1..=99999).contains(&weight) => (field.trim(), weight),
+                    _ => abort!(span, "invalid weight for text index field `{}`", field.trim(); help = "weights must be integers from 1 to 99999"),
+                },
+                None => (entry, 1),
+            };
+            if field.is_empty() {
+                abort!(
+                    span,
+                    "text index fields must be given as a comma separated list of `field` or `field:weight` entries"
+                );
+            }
+            if fields.iter().any(|(name, _)| name == field) {
+                abort!(span, "text index field `{}` is listed more than once", field);
+            }
+            fields.push((field.to_string(), weight));
+        }
+        // All options are given explicitly, matching what the server reports for the index, so
+        // that `sync` does not needlessly recreate it.
+        let names = fields.iter().map(|(name, _)| name);
+        let weights = fields.iter().map(|(name, weight)| {
+            let weight = proc_macro2::Literal::i32_unsuffixed(*weight);
+            quote!(#name: #weight)
         });
-        Self { keys, options }
+        let default_language = src.default_language.unwrap_or_else(|| "english".to_string());
+        let language_override = src.language_override.unwrap_or_else(|| "language".to_string());
+        let name = src.name.map(|name| quote!("name": #name,));
+        let keys = quote!(wither::bson::doc! {#(#names: "text"),*});
+        let options = quote!(wither::bson::doc! {
+            #name
+            "weights": {#(#weights),*},
+            "default_language": #default_language,
+            "language_override": #language_override,
+        });
+        Self { keys, options: Some(options), span }
     }
 }
 
@@ -513,6 +1670,111 @@ pub struct IndexModelTokens {
     pub keys: proc_macro2::TokenStream,
     /// The token stream to use as an index model's options.
     pub options: Option<proc_macro2::TokenStream>,
+    /// The span of the original keys attribute.
+    pub span: proc_macro2::Span,
+}
+
+/// Get the serialized name given by a serde attr such as `rename = "..."`, or by its
+/// `rename(serialize = "...")` form, if the given attr matches the given key.
+fn serde_serialized_name(attr: &syn::Meta, key: &str) -> Option<String> {
+    if !attr.path().is_ident(key) {
+        return None;
+    }
+    match attr {
+        syn::Meta::NameValue(syn::MetaNameValue { lit: syn::Lit::Str(lit), .. }) => Some(lit.value()),
+        syn::Meta::List(list) => list.nested.iter().find_map(|nested| match nested {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(val)) if val.path.is_ident("serialize") => match &val.lit {
+                syn::Lit::Str(lit) => Some(lit.value()),
+                _ => None,
+            },
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+/// Apply the given serde `rename_all` rule to the given field name, exactly as serde does.
+///
+/// Serde assumes that field names are already snake case. Returns `None` for unknown rules.
+fn apply_serde_rename_rule(rule: &str, field: &str) -> Option<String> {
+    let pascal = || {
+        let mut pascal = String::new();
+        let mut capitalize = true;
+        for ch in field.chars() {
+            if ch == '_' {
+                capitalize = true;
+            } else if capitalize {
+                pascal.push(ch.to_ascii_uppercase());
+                capitalize = false;
+            } else {
+                pascal.push(ch);
+            }
+        }
+        pascal
+    };
+    Some(match rule {
+        "lowercase" | "snake_case" => field.to_string(),
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => field.to_ascii_uppercase(),
+        "PascalCase" => pascal(),
+        "camelCase" => {
+            let pascal = pascal();
+            match pascal.chars().next() {
+                Some(first) => first.to_ascii_lowercase().to_string() + &pascal[first.len_utf8()..],
+                None => pascal,
+            }
+        }
+        "kebab-case" => field.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => field.to_ascii_uppercase().replace('_', "-"),
+        _ => return None,
+    })
+}
+
+impl IndexModelTokens {
+    /// Get all string literal keys declared at the top level of this index's `doc!{...}`.
+    ///
+    /// Keys which are not string literals, or keys which are not given as a `doc!` invocation,
+    /// are skipped.
+    fn key_paths(&self) -> Vec<String> {
+        self.key_entries().into_iter().map(|(key, _)| key).collect()
+    }
+
+    /// Get all string literal keys declared at the top level of this index's `doc!{...}`, along
+    /// with the tokens of their corresponding values.
+    fn key_entries(&self) -> Vec<(String, Vec<proc_macro2::TokenTree>)> {
+        doc_entries(&self.keys)
+    }
+
+    /// Get all string literal keys declared at the top level of this index's options `doc!{...}`,
+    /// along with the tokens of their corresponding values.
+    fn option_entries(&self) -> Vec<(String, Vec<proc_macro2::TokenTree>)> {
+        self.options.as_ref().map(doc_entries).unwrap_or_default()
+    }
+}
+
+/// Get all string literal keys declared at the top level of the given `doc!{...}` tokens, along
+/// with the tokens of their corresponding values.
+///
+/// Keys which are not string literals, or tokens which are not a `doc!` invocation, are skipped.
+fn doc_entries(tokens: &proc_macro2::TokenStream) -> Vec<(String, Vec<proc_macro2::TokenTree>)> {
+    use proc_macro2::{Delimiter, TokenTree};
+    let body = tokens.clone().into_iter().find_map(|tt| match tt {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => Some(group.stream()),
+        _ => None,
+    });
+    let tokens: Vec<_> = match body {
+        Some(body) => body.into_iter().collect(),
+        None => return vec![],
+    };
+    tokens
+        .split(|tt| matches!(tt, TokenTree::Punct(punct) if punct.as_char() == ','))
+        .filter_map(|entry| match entry {
+            [TokenTree::Literal(lit), TokenTree::Punct(punct), value @ ..] if punct.as_char() == ':' => {
+                let key = syn::parse2::<syn::LitStr>(TokenTree::Literal(lit.clone()).into()).ok()?;
+                Some((key.value(), value.to_vec()))
+            }
+            _ => None,
+        })
+        .collect()
 }
 
 impl quote::ToTokens for IndexModelTokens {
@@ -0,0 +1,12 @@
+use serde::{Serialize, Deserialize};
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+struct BadModel {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    #[serde(rename="_id")]
+    pub legacy_id: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use serde::{Serialize, Deserialize};
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+struct BadModel {
+    #[serde(rename="_id")]
+    id: wither::bson::oid::ObjectId,
+}
+
+fn main() {}
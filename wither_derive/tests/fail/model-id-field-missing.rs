@@ -0,0 +1,10 @@
+use serde::{Serialize, Deserialize};
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(id_field="base.id")]
+struct BadModel {
+    name: String,
+}
+
+fn main() {}
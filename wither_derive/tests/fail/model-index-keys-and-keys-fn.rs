@@ -0,0 +1,17 @@
+use serde::{Serialize, Deserialize};
+use wither::bson::{doc, Document};
+use wither::prelude::*;
+
+fn email_index_keys() -> Document {
+    doc! {"email": 1}
+}
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(index(keys=r#"doc!{"email": 1}"#, keys_fn="email_index_keys"))]
+struct User {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    id: Option<wither::bson::oid::ObjectId>,
+    email: String,
+}
+
+fn main() {}
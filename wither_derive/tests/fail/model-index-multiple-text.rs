@@ -0,0 +1,15 @@
+use serde::{Serialize, Deserialize};
+use wither::bson::doc;
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(index(keys=r#"doc!{"title": "text"}"#))]
+#[model(index(keys=r#"doc!{"body": "text"}"#))]
+struct BadModel {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub title: String,
+    pub body: String,
+}
+
+fn main() {}
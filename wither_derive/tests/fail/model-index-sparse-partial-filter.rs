@@ -0,0 +1,16 @@
+use serde::{Serialize, Deserialize};
+use wither::bson::doc;
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(index(
+    keys=r#"doc!{"email": 1}"#,
+    options=r#"doc!{"unique": true, "sparse": true, "partialFilterExpression": doc!{"email": doc!{"$exists": true}}}"#,
+))]
+struct BadModel {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub email: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#![deny(deprecated)]
+
+use serde::{Serialize, Deserialize};
+use wither::bson::doc;
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(index(keys=r#"doc!{"profle.settings.theme": 1}"#))]
+struct Derived {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub profile: wither::bson::Document,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use serde::{Serialize, Deserialize};
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(new(email, nmae))]
+struct BadModel {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub name: String,
+    pub email: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use serde::{Serialize, Deserialize};
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+struct Model0 {
+    #[model(no_overwrite)]
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use serde::{Serialize, Deserialize};
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(read_preference(mode="secondary", max_staleness_secs=30))]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use serde::{Serialize, Deserialize};
+use wither::bson::Document;
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(snapshot_field="snapshot")]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub snapshot: Option<Document>,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use serde::{Serialize, Deserialize};
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(text_index(fields="title:high"))]
+struct Derived {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub title: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use serde::{Serialize, Deserialize};
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(validator=r#"doc!{"email": {"$exists": true}}"#, validation_action="ignore")]
+struct Derived {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+fn main() {}
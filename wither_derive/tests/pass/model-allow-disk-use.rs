@@ -0,0 +1,20 @@
+use serde::{Serialize, Deserialize};
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(allow_disk_use)]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+#[derive(Serialize, Deserialize, Model)]
+struct Model1 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+fn main() {
+    assert!(Model0::default_allow_disk_use());
+    assert!(!Model1::default_allow_disk_use());
+}
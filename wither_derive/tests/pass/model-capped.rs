@@ -0,0 +1,28 @@
+use serde::{Serialize, Deserialize};
+use wither::{CappedOptions, Model};
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(capped(size=4096, max=100))]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(capped(size=8192))]
+struct Model1 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+#[derive(Serialize, Deserialize, Model)]
+struct Model2 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+fn main() {
+    assert_eq!(Model0::capped(), Some(CappedOptions::new(4096, Some(100))));
+    assert_eq!(Model1::capped(), Some(CappedOptions::new(8192, None)));
+    assert_eq!(Model2::capped(), None);
+}
@@ -0,0 +1,61 @@
+use serde::{Serialize, Deserialize};
+use wither::bson::doc;
+use wither::Model;
+
+#[derive(Default, Serialize, Deserialize, Model)]
+#[model(field_consts)]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub email: String,
+    #[serde(rename="displayName")]
+    pub display_name: String,
+    pub r#type: String,
+    #[serde(skip)]
+    pub cached: bool,
+}
+
+#[derive(Default, Serialize, Deserialize, Model)]
+#[model(field_consts)]
+#[serde(rename_all="camelCase")]
+struct Model1 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub created_at: i64,
+}
+
+#[derive(Default, Serialize, Deserialize, Model)]
+#[model(field_consts)]
+#[serde(rename_all(serialize="SCREAMING-KEBAB-CASE", deserialize="snake_case"))]
+struct Model2 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub created_at: i64,
+    #[serde(rename(serialize="v2"))]
+    pub version: i64,
+}
+
+#[derive(Default, Serialize, Deserialize, Model)]
+#[model(field_consts)]
+#[serde(rename_all="PascalCase")]
+struct Model3 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub url_v2_path: String,
+}
+
+fn main() {
+    assert_eq!(Model0::FIELD_ID, "_id");
+    assert_eq!(Model0::FIELD_EMAIL, "email");
+    assert_eq!(Model0::FIELD_DISPLAY_NAME, "displayName");
+    assert_eq!(Model0::FIELD_TYPE, "type");
+    assert_eq!(doc!{Model0::FIELD_EMAIL: "test@test.com"}, doc!{"email": "test@test.com"});
+
+    assert_eq!(Model1::FIELD_ID, "_id");
+    assert_eq!(Model1::FIELD_CREATED_AT, "createdAt");
+
+    assert_eq!(Model2::FIELD_CREATED_AT, "CREATED-AT");
+    assert_eq!(Model2::FIELD_VERSION, "v2");
+
+    assert_eq!(Model3::FIELD_URL_V2_PATH, "UrlV2Path");
+}
@@ -0,0 +1,32 @@
+use serde::{Serialize, Deserialize};
+use wither::bson::doc;
+use wither::mongodb::options::Hint;
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(hint="email_1", index(keys=r#"doc!{"email": 1}"#))]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub email: String,
+}
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(hint=r#"doc!{"email": 1}"#)]
+struct Model1 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub email: String,
+}
+
+#[derive(Serialize, Deserialize, Model)]
+struct Model2 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+fn main() {
+    assert!(matches!(Model0::default_hint(), Some(Hint::Name(name)) if name == "email_1"));
+    assert!(matches!(Model1::default_hint(), Some(Hint::Keys(keys)) if keys == doc!{"email": 1}));
+    assert!(Model2::default_hint().is_none());
+}
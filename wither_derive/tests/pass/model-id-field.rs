@@ -0,0 +1,39 @@
+use serde::{Serialize, Deserialize};
+use wither::bson::oid::ObjectId;
+use wither::Model;
+
+#[derive(Default, Serialize, Deserialize, Model)]
+#[model(id_field="oid")]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub oid: Option<ObjectId>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct BaseFields {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<ObjectId>,
+    pub created_at: i64,
+}
+
+#[derive(Default, Serialize, Deserialize, Model)]
+#[model(id_field="base.id")]
+struct Model1 {
+    #[serde(flatten)]
+    pub base: BaseFields,
+    pub name: String,
+}
+
+fn main() {
+    let id = ObjectId::new();
+
+    let mut model0 = Model0::default();
+    model0.set_id(id);
+    assert_eq!(model0.oid, Some(id));
+    assert_eq!(model0.id(), Some(id));
+
+    let mut model1 = Model1::default();
+    model1.set_id(id);
+    assert_eq!(model1.base.id, Some(id));
+    assert_eq!(model1.id(), Some(id));
+}
@@ -0,0 +1,39 @@
+use serde::{Serialize, Deserialize};
+use wither::bson::{doc, Document};
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(
+    index(keys_fn="Model0::email_index_keys", options_fn="unique_options"),
+    index(keys_fn="Model0::created_index_keys"),
+    index(keys=r#"doc!{"email": -1}"#, options_fn="unique_options"),
+)]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub email: String,
+    pub created_at: i64,
+}
+
+impl Model0 {
+    fn email_index_keys() -> Document {
+        doc! {"email": 1}
+    }
+
+    fn created_index_keys() -> Document {
+        doc! {"created_at": -1}
+    }
+}
+
+fn unique_options() -> Document {
+    doc! {"unique": true}
+}
+
+fn main() {
+    let indexes = Model0::indexes();
+    let keys: Vec<_> = indexes.iter().map(|index| index.keys.clone()).collect();
+    assert_eq!(keys, vec![doc!{"email": 1}, doc!{"created_at": -1}, doc!{"email": -1}]);
+    assert_eq!(indexes[0].options, Some(doc!{"unique": true}));
+    assert_eq!(indexes[1].options, None);
+    assert_eq!(indexes[2].options, Some(doc!{"unique": true}));
+}
@@ -0,0 +1,47 @@
+#![deny(deprecated)]
+
+use serde::{Serialize, Deserialize};
+use wither::bson::{doc, Document};
+use wither::Model;
+
+#[derive(Default, Serialize, Deserialize, Model)]
+#[model(
+    index(keys=r#"doc!{"profile.settings.theme": 1}"#),
+    index(keys=r#"doc!{"prefs.color": 1, "email": 1}"#),
+)]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub profile: Document,
+    #[serde(rename="prefs")]
+    pub preferences: Document,
+    pub email: String,
+}
+
+#[derive(Default, Serialize, Deserialize, Model)]
+#[model(
+    index(keys=r#"doc!{"userProfile.settings.theme": 1}"#),
+    index(keys=r#"doc!{"createdAt": -1, "lastLogin": 1}"#),
+)]
+#[serde(rename_all="camelCase")]
+struct Model1 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub user_profile: Document,
+    pub created_at: i64,
+    #[serde(rename(serialize="lastLogin", deserialize="last_login"))]
+    pub last_seen: i64,
+}
+
+#[derive(Default, Serialize, Deserialize, Model)]
+#[model(skip_serde_checks, index(keys=r#"doc!{"dynamic.settings.theme": 1}"#))]
+struct Model2 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+fn main() {
+    assert_eq!(Model0::indexes()[0].keys, doc!{"profile.settings.theme": 1});
+    assert_eq!(Model1::indexes()[0].keys, doc!{"userProfile.settings.theme": 1});
+    assert_eq!(Model2::indexes()[0].keys, doc!{"dynamic.settings.theme": 1});
+}
@@ -0,0 +1,28 @@
+#![deny(deprecated)]
+
+use serde::{Serialize, Deserialize};
+use wither::bson::{doc, Document};
+use wither::Model;
+
+#[derive(Default, Serialize, Deserialize, Model)]
+#[model(
+    index(keys=r#"doc!{"tags.$**": 1}"#),
+    index(keys=r#"doc!{"$**": 1}"#, options=r#"doc!{"wildcardProjection": {"tags": 0}}"#),
+    index(keys=r#"doc!{"$**": "text"}"#),
+    index(keys=r#"doc!{"loc": "2dsphere", "email": -1}"#),
+)]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub tags: Vec<Document>,
+    pub loc: Document,
+    pub email: String,
+}
+
+fn main() {
+    let indexes = Model0::indexes();
+    assert_eq!(indexes[0].keys, doc!{"tags.$**": 1});
+    assert_eq!(indexes[1].options, Some(doc!{"wildcardProjection": {"tags": 0}}));
+    assert_eq!(indexes[2].keys, doc!{"$**": "text"});
+    assert_eq!(indexes[3].keys, doc!{"loc": "2dsphere", "email": -1});
+}
@@ -0,0 +1,40 @@
+use serde::{Serialize, Deserialize};
+use wither::bson::doc;
+use wither::{IndexModel, Model};
+
+fn tenant_indexes() -> Vec<IndexModel> {
+    vec![IndexModel::new(doc!{"tenant_id": 1}, None)]
+}
+
+mod shared {
+    use wither::bson::doc;
+    use wither::IndexModel;
+
+    pub fn created_indexes() -> Vec<IndexModel> {
+        vec![IndexModel::new(doc!{"created_at": -1}, None)]
+    }
+}
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(index(keys=r#"doc!{"email": 1}"#), indexes_from="tenant_indexes", indexes_from="shared::created_indexes")]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub email: String,
+    pub tenant_id: String,
+    pub created_at: i64,
+}
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(indexes_from="tenant_indexes")]
+struct Model1 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub tenant_id: String,
+}
+
+fn main() {
+    let keys: Vec<_> = Model0::indexes().into_iter().map(|index| index.keys).collect();
+    assert_eq!(keys, vec![doc!{"email": 1}, doc!{"tenant_id": 1}, doc!{"created_at": -1}]);
+    assert_eq!(Model1::indexes().len(), 1);
+}
@@ -0,0 +1,22 @@
+use std::time::Duration;
+
+use serde::{Serialize, Deserialize};
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(max_time_ms=5000)]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+#[derive(Serialize, Deserialize, Model)]
+struct Model1 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+fn main() {
+    assert_eq!(Model0::default_max_time(), Some(Duration::from_millis(5000)));
+    assert_eq!(Model1::default_max_time(), None);
+}
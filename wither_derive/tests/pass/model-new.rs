@@ -0,0 +1,22 @@
+use serde::{Serialize, Deserialize};
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(new(email, name))]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub name: String,
+    pub email: String,
+    pub tags: Vec<String>,
+    pub login_count: i64,
+}
+
+fn main() {
+    let model = Model0::new("test@test.com".to_string(), "test".to_string());
+    assert_eq!(model.id, None);
+    assert_eq!(model.email, "test@test.com");
+    assert_eq!(model.name, "test");
+    assert!(model.tags.is_empty());
+    assert_eq!(model.login_count, 0);
+}
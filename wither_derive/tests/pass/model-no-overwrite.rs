@@ -0,0 +1,26 @@
+use serde::{Serialize, Deserialize};
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[serde(rename_all="camelCase")]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub email: String,
+    #[model(no_overwrite)]
+    pub login_count: i64,
+    #[model(no_overwrite)]
+    #[serde(rename="updatedBy")]
+    pub updater: String,
+}
+
+#[derive(Serialize, Deserialize, Model)]
+struct Model1 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+fn main() {
+    assert_eq!(Model0::no_overwrite_fields(), &["loginCount", "updatedBy"]);
+    assert!(Model1::no_overwrite_fields().is_empty());
+}
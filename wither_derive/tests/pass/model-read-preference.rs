@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use serde::{Serialize, Deserialize};
+use wither::bson::doc;
+use wither::mongodb::options::{ReadPreference, SelectionCriteria};
+use wither::prelude::*;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(read_preference(mode="secondary", max_staleness_secs=90, tags=r#"doc!{"region": "us"}"#))]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(read_preference(mode="primary"))]
+struct Model1 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(read_preference(mode="secondary_preferred"))]
+struct Model2 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+fn main() {
+    let options = match Model0::selection_criteria() {
+        Some(SelectionCriteria::ReadPreference(ReadPreference::Secondary { options })) => options,
+        _ => panic!("expected a secondary read preference"),
+    };
+    let mut tags = HashMap::new();
+    tags.insert("region".to_string(), "us".to_string());
+    assert_eq!(options.tag_sets, Some(vec![tags]));
+    assert_eq!(options.max_staleness, Some(Duration::from_secs(90)));
+
+    assert_eq!(Model1::selection_criteria(), Some(SelectionCriteria::ReadPreference(ReadPreference::Primary)));
+    assert!(matches!(
+        Model2::selection_criteria(),
+        Some(SelectionCriteria::ReadPreference(ReadPreference::SecondaryPreferred { options })) if options.tag_sets.is_none() && options.max_staleness.is_none()
+    ));
+}
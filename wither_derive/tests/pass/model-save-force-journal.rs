@@ -0,0 +1,28 @@
+use serde::{Serialize, Deserialize};
+use wither::Model;
+
+#[derive(Default, Serialize, Deserialize, Model)]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+#[derive(Default, Serialize, Deserialize, Model)]
+#[model(save_force_journal=false)]
+struct Model1 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+#[derive(Default, Serialize, Deserialize, Model)]
+#[model(save_force_journal=true)]
+struct Model2 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+fn main() {
+    assert!(Model0::save_forces_journal());
+    assert!(!Model1::save_forces_journal());
+    assert!(Model2::save_forces_journal());
+}
@@ -0,0 +1,32 @@
+use serde::{Serialize, Deserialize};
+use wither::bson::Document;
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(snapshot_field="snapshot")]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub email: String,
+    #[serde(skip)]
+    pub snapshot: Option<Document>,
+}
+
+#[derive(Serialize, Deserialize, Model)]
+struct Model1 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+fn main() {
+    let mut model = Model0 { id: None, email: "test@test.com".to_string(), snapshot: None };
+    assert!(model.is_dirty().unwrap());
+    model.mark_clean().unwrap();
+    assert!(!model.is_dirty().unwrap());
+    model.email = "new@test.com".to_string();
+    assert!(model.is_dirty().unwrap());
+
+    let mut model = Model1 { id: None };
+    assert!(model.snapshot().is_none());
+    assert!(model.mark_clean().is_err());
+}
@@ -0,0 +1,43 @@
+#![deny(deprecated)]
+
+use serde::{Serialize, Deserialize};
+use wither::bson::doc;
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(text_index(fields="title:10, body:5, tags", default_language="spanish", language_override="lang"))]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub title: String,
+    pub body: String,
+    pub tags: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(text_index(fields="title", name="search"), index(keys=r#"doc!{"title": 1}"#))]
+struct Model1 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub title: String,
+}
+
+fn main() {
+    let indexes = Model0::indexes();
+    assert_eq!(indexes[0].keys, doc!{"title": "text", "body": "text", "tags": "text"});
+    assert_eq!(indexes[0].options, Some(doc!{
+        "weights": {"title": 10, "body": 5, "tags": 1},
+        "default_language": "spanish",
+        "language_override": "lang",
+    }));
+
+    let indexes = Model1::indexes();
+    assert_eq!(indexes.len(), 2);
+    assert_eq!(indexes[0].keys, doc!{"title": "text"});
+    assert_eq!(indexes[0].options, Some(doc!{
+        "name": "search",
+        "weights": {"title": 1},
+        "default_language": "english",
+        "language_override": "language",
+    }));
+}
@@ -0,0 +1,21 @@
+use std::convert::TryFrom;
+
+use serde::{Serialize, Deserialize};
+use wither::bson::{doc, Document};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, wither::Model)]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub email: String,
+}
+
+fn main() {
+    let model = Model0 { id: None, email: "test@test.com".to_string() };
+    let document = Document::try_from(&model).expect("model should serialize");
+    assert_eq!(document, doc!{"email": "test@test.com"});
+
+    let parsed = Model0::try_from(document).expect("document should deserialize");
+    assert_eq!(parsed, model);
+    assert!(Model0::try_from(doc!{"email": 1}).is_err());
+}
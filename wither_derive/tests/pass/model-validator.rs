@@ -0,0 +1,29 @@
+use serde::{Serialize, Deserialize};
+use wither::bson::doc;
+use wither::mongodb::options::{ValidationAction, ValidationLevel};
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(validator=r#"doc!{"$jsonSchema": {"required": ["email"]}}"#, validation_level="moderate", validation_action="warn")]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub email: String,
+}
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(validator=r#"doc!{"email": {"$type": "string"}}"#)]
+struct Model1 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub email: String,
+}
+
+fn main() {
+    assert_eq!(Model0::validator(), Some(doc!{"$jsonSchema": {"required": ["email"]}}));
+    assert_eq!(Model0::validation_level(), Some(ValidationLevel::Moderate));
+    assert_eq!(Model0::validation_action(), Some(ValidationAction::Warn));
+    assert_eq!(Model1::validator(), Some(doc!{"email": {"$type": "string"}}));
+    assert_eq!(Model1::validation_level(), None);
+    assert_eq!(Model1::validation_action(), None);
+}
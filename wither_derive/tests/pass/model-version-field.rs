@@ -0,0 +1,31 @@
+use serde::{Serialize, Deserialize};
+use wither::Model;
+
+#[derive(Serialize, Deserialize, Model)]
+#[model(version_field="version")]
+struct Model0 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub version: i64,
+}
+
+#[derive(Serialize, Deserialize, Model)]
+#[serde(rename_all="camelCase")]
+#[model(version_field="doc_version")]
+struct Model1 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+    pub doc_version: i64,
+}
+
+#[derive(Serialize, Deserialize, Model)]
+struct Model2 {
+    #[serde(rename="_id", skip_serializing_if="Option::is_none")]
+    pub id: Option<wither::bson::oid::ObjectId>,
+}
+
+fn main() {
+    assert_eq!(Model0::version_field(), Some("version"));
+    assert_eq!(Model1::version_field(), Some("docVersion"));
+    assert_eq!(Model2::version_field(), None);
+}